@@ -1,13 +1,22 @@
 #![no_std]
 
 use shared::{
-    constants::{MIN_VALIDATORS, RESUME_TIME_DELAY, UPGRADE_TIME_LOCK_SECS},
+    constants::{
+        LAUNCH_ESCROW_ID_FLAG, REFUND_RATE_SCALE, RESUME_TIME_DELAY, UPGRADE_TIME_LOCK_SECS,
+    },
     errors::Error,
     events::*,
-    types::{Amount, EscrowInfo, Hash, Milestone, MilestoneStatus, PauseState, PendingUpgrade},
-    MIN_APPROVAL_THRESHOLD, MAX_APPROVAL_THRESHOLD,
+    types::{
+        AbandonmentSnapshot, Amount, ChallengeConfig, EscrowInfo, Hash, Milestone,
+        MilestoneDispute, MilestoneReview, MilestoneStatus, PauseState, PendingUpgrade, Tranche,
+        TrancheStatus, Unbonding, ValidatorBondConfig, ValidatorChange, ValidatorProposal,
+        VoteWeighting,
+    },
+    utils::launch_escrow_id,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token::TokenClient, Address, BytesN, Env, Map, Vec,
 };
-use soroban_sdk::{contract, contractimpl, token::TokenClient, Address, BytesN, Env, Vec};
 
 mod storage;
 mod validation;
@@ -17,6 +26,21 @@ mod tests;
 
 use storage::*;
 
+// Interface for ReputationContract
+#[soroban_sdk::contractclient(name = "ReputationContractClient")]
+pub trait ReputationContractTrait {
+    fn get_profile(env: Env, user: Address) -> Result<ReputationProfile, Error>;
+}
+
+/// Profile returned by the ReputationContract (badges as their u32 codes)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationProfile {
+    pub user: Address,
+    pub score: i128,
+    pub badges: Vec<u32>,
+}
+
 #[contract]
 pub struct EscrowContract;
 
@@ -49,50 +73,167 @@ impl EscrowContract {
     ) -> Result<(), Error> {
         creator.require_auth();
 
-        // Validate inputs
-        if (validators.len() as u32) < MIN_VALIDATORS {
+        // Ids with the launch flag are reserved for escrows ProjectLaunch funds
+        if project_id & LAUNCH_ESCROW_ID_FLAG != 0 {
             return Err(Error::InvalidInput);
         }
 
+        // Validate inputs
+        validation::validate_escrow_config(&validators, approval_threshold)?;
+
         // Check if escrow already exists
         if escrow_exists(&env, project_id) {
             return Err(Error::AlreadyInitialized);
         }
 
-        if approval_threshold < MIN_APPROVAL_THRESHOLD || approval_threshold > MAX_APPROVAL_THRESHOLD {
-            return Err(Error::InvalidInput);
-        }
-
-        // Create escrow info
-        let escrow = EscrowInfo {
+        create_escrow(
+            &env,
             project_id,
-            creator: creator.clone(),
-            token: token.clone(),
-            total_deposited: 0,
-            released_amount: 0,
+            creator,
+            token,
             validators,
             approval_threshold,
-        };
+        );
 
-        // Store escrow
-        set_escrow(&env, project_id, &escrow);
+        Ok(())
+    }
+
+    /// Set the ProjectLaunch contract trusted to fund escrows on project completion
+    ///
+    /// # Arguments
+    /// * `launch_contract` - Address of the ProjectLaunch contract
+    pub fn set_launch_contract(env: Env, launch_contract: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
 
-        // Initialize milestone counter
-        set_milestone_counter(&env, project_id, 0);
+        set_launch_contract(&env, &launch_contract);
+
+        Ok(())
+    }
+
+    /// Get the trusted ProjectLaunch contract address, if configured
+    pub fn get_launch_contract(env: Env) -> Option<Address> {
+        get_launch_contract(&env)
+    }
+
+    /// Set how many times a rejected milestone may be resubmitted
+    ///
+    /// # Arguments
+    /// * `max_resubmissions` - Resubmissions allowed after the first rejection
+    pub fn set_max_resubmissions(env: Env, max_resubmissions: u32) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        set_max_resubmissions(&env, max_resubmissions);
+
+        Ok(())
+    }
+
+    /// Get how many times a rejected milestone may be resubmitted
+    pub fn get_max_resubmissions(env: Env) -> u32 {
+        get_max_resubmissions(&env)
+    }
+
+    /// Fund an escrow with the proceeds of a completed ProjectLaunch raise
+    ///
+    /// Only callable by the configured ProjectLaunch contract, which must
+    /// authorize the transfer of `amount` of `token` to this contract. The
+    /// escrow lives under `launch_escrow_id(project_id)`, an id `initialize`
+    /// refuses, so only this call can create it. It is initialized on first
+    /// use and topped up on later calls.
+    ///
+    /// # Arguments
+    /// * `project_id` - ProjectLaunch project identifier
+    /// * `creator` - Address of the project creator
+    /// * `token` - Token address for the escrow
+    /// * `validators` - Validators to use if the escrow has to be initialized
+    /// * `approval_threshold` - Threshold to use if the escrow has to be initialized
+    /// * `amount` - Amount transferred into the escrow
+    pub fn deposit_from_launch(
+        env: Env,
+        project_id: u64,
+        creator: Address,
+        token: Address,
+        validators: Vec<Address>,
+        approval_threshold: u32,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        let launch_contract = get_launch_contract(&env).ok_or(Error::NotInitialized)?;
+        launch_contract.require_auth();
+
+        let escrow_id = launch_escrow_id(project_id);
+
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if is_abandoned(&env, escrow_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        if !escrow_exists(&env, escrow_id) {
+            validation::validate_escrow_config(&validators, approval_threshold)?;
+            create_escrow(
+                &env,
+                escrow_id,
+                creator.clone(),
+                token.clone(),
+                validators,
+                approval_threshold,
+            );
+        }
+
+        let mut escrow = get_escrow(&env, escrow_id)?;
+        if escrow.creator != creator || escrow.token != token {
+            return Err(Error::InvalidInput);
+        }
+
+        let token_client = TokenClient::new(&env, &token);
+        token_client.transfer(&launch_contract, &env.current_contract_address(), &amount);
+
+        let recorded = get_recorded_balance(&env, &token)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        set_recorded_balance(&env, &token, recorded);
+
+        escrow.total_deposited = escrow
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        set_escrow(&env, escrow_id, &escrow);
+
+        record_deposit(&env, escrow_id, &launch_contract, amount)?;
 
-        // Emit event
         env.events()
-            .publish((ESCROW_INITIALIZED,), (project_id, creator, token));
+            .publish((FUNDS_LOCKED,), (escrow_id, amount, launch_contract));
 
         Ok(())
     }
 
+    /// Get the total amount deposited into an escrow by a given source
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `source` - Address the deposits came from
+    pub fn get_deposit_from_source(env: Env, project_id: u64, source: Address) -> Amount {
+        get_deposit_from_source(&env, project_id, &source)
+    }
+
     /// Deposit funds into the escrow
     ///
+    /// Transfers `amount` of the escrow token from `from` into this contract.
+    ///
     /// # Arguments
     /// * `project_id` - Project identifier
-    /// * `amount` - Amount to deposit (note: actual token transfer would be handled separately)
-    pub fn deposit(env: Env, project_id: u64, amount: Amount) -> Result<(), Error> {
+    /// * `from` - Address paying the deposit
+    /// * `amount` - Amount to deposit
+    pub fn deposit(env: Env, project_id: u64, from: Address, amount: Amount) -> Result<(), Error> {
+        from.require_auth();
+
         // Get escrow
         let mut escrow = get_escrow(&env, project_id)?;
 
@@ -105,32 +246,73 @@ impl EscrowContract {
             return Err(Error::ContractPaused);
         }
 
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
         // Update total deposited
         escrow.total_deposited = escrow
             .total_deposited
             .checked_add(amount)
             .ok_or(Error::InvalidInput)?;
+        let recorded = get_recorded_balance(&env, &escrow.token)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+
+        // Pull the tokens in
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&from, &env.current_contract_address(), &amount);
 
         // Store updated escrow
         set_escrow(&env, project_id, &escrow);
+        set_recorded_balance(&env, &escrow.token, recorded);
+        record_deposit(&env, project_id, &from, amount)?;
 
         // Emit event
-        env.events().publish((FUNDS_LOCKED,), (project_id, amount));
+        env.events()
+            .publish((FUNDS_LOCKED,), (project_id, amount, from));
 
         Ok(())
     }
 
+    /// Get the amount of a token the escrow books say this contract holds,
+    /// i.e. deposits minus releases across all escrows in that token
+    ///
+    /// # Arguments
+    /// * `token` - Token address
+    pub fn get_recorded_balance(env: Env, token: Address) -> Amount {
+        get_recorded_balance(&env, &token)
+    }
+
+    /// Get the difference between this contract's actual balance of a token
+    /// and its recorded balance. Positive values are untracked tokens sent
+    /// directly to the contract; negative values are a shortfall.
+    ///
+    /// # Arguments
+    /// * `token` - Token address
+    pub fn get_unrecorded_balance(env: Env, token: Address) -> Amount {
+        let actual = TokenClient::new(&env, &token).balance(&env.current_contract_address());
+        actual - get_recorded_balance(&env, &token)
+    }
+
     /// Create a new milestone
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
     /// * `description_hash` - Hash of the milestone description
     /// * `amount` - Amount to be released when milestone is approved
+    /// * `deadline` - Optional time by which proof must be submitted
+    /// * `voting_window` - Optional seconds validators have to decide after a submission
+    /// * `tranches` - Optional release schedule; the whole amount is released on
+    ///   approval if omitted
     pub fn create_milestone(
         env: Env,
         project_id: u64,
         description_hash: Hash,
         amount: Amount,
+        deadline: Option<u64>,
+        voting_window: Option<u64>,
+        tranches: Option<Vec<Tranche>>,
     ) -> Result<(), Error> {
         // Get escrow to verify it exists and get creator
         let escrow = get_escrow(&env, project_id)?;
@@ -141,6 +323,26 @@ impl EscrowContract {
             return Err(Error::InvalidInput);
         }
 
+        // Validate schedule
+        if deadline.is_some_and(|deadline| deadline <= env.ledger().timestamp())
+            || voting_window == Some(0)
+        {
+            return Err(Error::InvalidInput);
+        }
+        let tranches = match tranches {
+            Some(tranches) => {
+                validation::validate_tranches(&tranches)?;
+                tranches
+            }
+            None => Vec::from_array(
+                &env,
+                [Tranche {
+                    share: 10000,
+                    delay: 0,
+                }],
+            ),
+        };
+
         // Validate that total milestone amounts don't exceed escrow total
         let total_milestones = get_total_milestone_amount(&env, project_id)?;
         let new_total = total_milestones
@@ -155,6 +357,10 @@ impl EscrowContract {
             return Err(Error::ContractPaused);
         }
 
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
         // Get next milestone ID
         let milestone_id = get_milestone_counter(&env, project_id)?;
         let next_id = milestone_id.checked_add(1).ok_or(Error::InvalidInput)?;
@@ -171,6 +377,18 @@ impl EscrowContract {
             approval_count: 0,
             rejection_count: 0,
             created_at: env.ledger().timestamp(),
+            resubmission_count: 0,
+            deadline,
+            voting_window,
+            submitted_at: 0,
+            tranches,
+            approved_at: 0,
+            released_amount: 0,
+            tranches_released: 0,
+            challenge_ends_at: 0,
+            total_weight: 0,
+            approval_weight: 0,
+            rejection_weight: 0,
         };
 
         // Store milestone
@@ -188,6 +406,9 @@ impl EscrowContract {
 
     /// Submit a milestone with proof
     ///
+    /// A rejected milestone can be resubmitted with a revised proof until the
+    /// resubmission limit is used up.
+    ///
     /// # Arguments
     /// * `project_id` - Project identifier
     /// * `milestone_id` - Milestone identifier
@@ -206,17 +427,37 @@ impl EscrowContract {
         let mut milestone = get_milestone(&env, project_id, milestone_id)?;
 
         // Validate milestone status
-        if milestone.status != MilestoneStatus::Pending {
-            return Err(Error::InvalidMilestoneStatus);
+        match milestone.status {
+            MilestoneStatus::Pending => {}
+            MilestoneStatus::Rejected => {
+                milestone.resubmission_count = milestone
+                    .resubmission_count
+                    .checked_add(1)
+                    .ok_or(Error::InvalidInput)?;
+            }
+            _ => return Err(Error::InvalidMilestoneStatus),
+        }
+
+        if milestone
+            .deadline
+            .is_some_and(|deadline| env.ledger().timestamp() > deadline)
+        {
+            return Err(Error::DeadlinePassed);
         }
 
         if is_paused(&env) {
             return Err(Error::ContractPaused);
         }
 
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
         // Update milestone
         milestone.status = MilestoneStatus::Submitted;
         milestone.proof_hash = proof_hash.clone();
+        milestone.submitted_at = env.ledger().timestamp();
+        snapshot_vote_weights(&env, &escrow, &mut milestone)?;
 
         // Store updated milestone
         set_milestone(&env, project_id, milestone_id, &milestone);
@@ -264,82 +505,64 @@ impl EscrowContract {
             return Err(Error::InvalidMilestoneStatus);
         }
 
+        if voting_closed(&env, &milestone) {
+            return Err(Error::DeadlinePassed);
+        }
+
         // Check if validator already voted
         if has_validator_voted(&env, project_id, milestone_id, &voter)? {
             return Err(Error::AlreadyVoted);
         }
 
+        // Validators joining after the submission have no say in it
+        let weight = get_vote_weights(&env, project_id, milestone_id)
+            .get(voter.clone())
+            .ok_or(Error::NotAValidator)?;
+
         // Update vote counts
         if approve {
             milestone.approval_count = milestone
                 .approval_count
                 .checked_add(1)
                 .ok_or(Error::InvalidInput)?;
+            milestone.approval_weight = milestone
+                .approval_weight
+                .checked_add(weight)
+                .ok_or(Error::InvalidInput)?;
         } else {
             milestone.rejection_count = milestone
                 .rejection_count
                 .checked_add(1)
                 .ok_or(Error::InvalidInput)?;
+            milestone.rejection_weight = milestone
+                .rejection_weight
+                .checked_add(weight)
+                .ok_or(Error::InvalidInput)?;
         }
 
         if is_paused(&env) {
             return Err(Error::ContractPaused);
         }
 
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
         // Record that this validator voted
-        set_validator_vote(&env, project_id, milestone_id, &voter)?;
+        set_validator_vote(&env, project_id, milestone_id, &voter, approve)?;
 
-        // Check if milestone is approved or rejected
-        let _total_votes = milestone.approval_count as u32 + milestone.rejection_count as u32;
-        // let required_approvals =
-        //     (escrow.validators.len() as u32 * MILESTONE_APPROVAL_THRESHOLD) / 10000;
-        let required_approvals =
-            (escrow.validators.len() as u32 * escrow.approval_threshold) / 10000;
+        // Check if milestone is approved or rejected. The required weight is
+        // rounded up by hand because `i128::div_ceil` is not stable on the
+        // crate's minimum supported Rust version; weights are never negative.
+        let required_weight =
+            (milestone.total_weight * escrow.approval_threshold as Amount + 9999) / 10000;
 
         // Check for majority approval
-        if milestone.approval_count as u32 >= required_approvals {
-            milestone.status = MilestoneStatus::Approved;
-
-            // Release funds
-            release_milestone_funds(&env, &mut escrow, &milestone)?;
-
-            // Perform token transfer to creator
-            let token_client = TokenClient::new(&env, &escrow.token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &escrow.creator,
-                &milestone.amount,
-            );
-
-            // Store updated escrow
-            set_escrow(&env, project_id, &escrow);
-
-            // Store updated milestone
-            set_milestone(&env, project_id, milestone_id, &milestone);
-
-            // Emit approval event
-            env.events().publish(
-                (MILESTONE_APPROVED,),
-                (project_id, milestone_id, milestone.approval_count),
-            );
-
-            // Emit fund release event
-            env.events().publish(
-                (FUNDS_RELEASED,),
-                (project_id, milestone_id, milestone.amount),
-            );
-        } else if milestone.rejection_count as u32
-            > escrow.validators.len() as u32 - required_approvals
-        {
+        if milestone.approval_weight >= required_weight {
+            accept_milestone(&env, &mut escrow, &mut milestone)?;
+        } else if milestone.rejection_weight > milestone.total_weight - required_weight {
             // Majority has rejected
-            milestone.status = MilestoneStatus::Rejected;
-            set_milestone(&env, project_id, milestone_id, &milestone);
-
-            // Emit rejection event
-            env.events().publish(
-                (MILESTONE_REJECTED,),
-                (project_id, milestone_id, milestone.rejection_count),
-            );
+            reject_milestone(&env, &mut milestone)?;
         } else {
             // Store updated milestone (vote recorded, but not yet finalized)
             set_milestone(&env, project_id, milestone_id, &milestone);
@@ -348,115 +571,1077 @@ impl EscrowContract {
         Ok(())
     }
 
-    /// Get escrow information
+    /// Expire a milestone that ran out of time. Callable by anyone.
+    ///
+    /// Pending or rejected milestones past their deadline become Expired and
+    /// their amount is no longer allocated. Submitted milestones whose voting
+    /// window closed without a decision resolve to the configured expiry outcome.
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
-    pub fn get_escrow(env: Env, project_id: u64) -> Result<EscrowInfo, Error> {
-        get_escrow(&env, project_id)
+    /// * `milestone_id` - Milestone identifier
+    pub fn expire_milestone(env: Env, project_id: u64, milestone_id: u64) -> Result<(), Error> {
+        let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        let now = env.ledger().timestamp();
+        match milestone.status {
+            MilestoneStatus::Pending | MilestoneStatus::Rejected => {
+                let overdue = match milestone.deadline {
+                    Some(deadline) => now > deadline,
+                    None => false,
+                };
+                if !overdue {
+                    return Err(Error::InvalidMilestoneStatus);
+                }
+
+                milestone.status = MilestoneStatus::Expired;
+                set_milestone(&env, project_id, milestone_id, &milestone);
+
+                env.events().publish(
+                    (MILESTONE_EXPIRED,),
+                    (project_id, milestone_id, milestone.amount),
+                );
+            }
+            MilestoneStatus::Submitted => {
+                if !voting_closed(&env, &milestone) {
+                    return Err(Error::InvalidMilestoneStatus);
+                }
+
+                record_missed_votes(&env, &escrow, &milestone)?;
+
+                if get_expiry_outcome(&env) == MilestoneStatus::Approved {
+                    accept_milestone(&env, &mut escrow, &mut milestone)?;
+                } else {
+                    reject_milestone(&env, &mut milestone)?;
+                }
+            }
+            _ => return Err(Error::InvalidMilestoneStatus),
+        }
+
+        Ok(())
     }
 
-    /// Get milestone information
+    /// Configure the challenge window for approved milestones. Admin only.
+    ///
+    /// While the window is open backers can dispute an approval; disputed
+    /// milestones are decided by a majority of the arbiters. To hand disputes
+    /// to governance, configure the governance contract as the sole arbiter.
     ///
     /// # Arguments
-    /// * `project_id` - Project identifier
-    /// * `milestone_id` - Milestone identifier
-    pub fn get_milestone(env: Env, project_id: u64, milestone_id: u64) -> Result<Milestone, Error> {
-        get_milestone(&env, project_id, milestone_id)
+    /// * `config` - Challenge window, dispute threshold and arbiter set
+    pub fn set_challenge_config(env: Env, config: ChallengeConfig) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        validation::validate_challenge_config(&config)?;
+        set_challenge_config(&env, &config);
+
+        Ok(())
     }
 
-    /// Get the total amount allocated to milestones
+    /// Get the challenge window applied to approved milestones
+    pub fn get_challenge_config(env: Env) -> ChallengeConfig {
+        get_challenge_config(&env)
+    }
+
+    /// Back a dispute of a milestone approval while its challenge window is
+    /// open. Once the supporting backers hold the dispute threshold share of
+    /// deposits the milestone is disputed and its funds stay frozen until the
+    /// arbiters decide.
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
-    pub fn get_total_milestone_amount(env: Env, project_id: u64) -> Result<Amount, Error> {
-        get_total_milestone_amount(&env, project_id)
+    /// * `milestone_id` - Milestone identifier
+    /// * `backer` - Address that deposited into the escrow
+    pub fn dispute_milestone(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        backer: Address,
+    ) -> Result<(), Error> {
+        backer.require_auth();
+
+        let stake = get_deposit_from_source(&env, project_id, &backer);
+        if stake <= 0 {
+            return Err(Error::Unauthorized);
+        }
+
+        back_dispute(&env, project_id, milestone_id, &backer, stake)?;
+        Ok(())
     }
 
-    /// Get remaining available balance in escrow
+    /// Back a dispute on behalf of a ProjectLaunch contributor of a launch
+    /// escrow. The launch contract attests the backer's `stake` out of its own
+    /// deposit. Returns when the challenge window closes.
     ///
     /// # Arguments
-    /// * `project_id` - Project identifier
-    pub fn get_available_balance(env: Env, project_id: u64) -> Result<Amount, Error> {
-        let escrow = get_escrow(&env, project_id)?;
-        Ok(escrow.total_deposited - escrow.released_amount)
+    /// * `project_id` - ProjectLaunch project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `backer` - Contributor disputing the approval
+    /// * `stake` - The contributor's share of the launch deposit
+    pub fn dispute_from_launch(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        backer: Address,
+        stake: Amount,
+    ) -> Result<u64, Error> {
+        let launch_contract = get_launch_contract(&env).ok_or(Error::NotInitialized)?;
+        launch_contract.require_auth();
+
+        let escrow_id = launch_escrow_id(project_id);
+        let deposited = get_deposit_from_source(&env, escrow_id, &launch_contract);
+        if stake <= 0 || stake > deposited {
+            return Err(Error::InvalidInput);
+        }
+
+        back_dispute(&env, escrow_id, milestone_id, &backer, stake)
     }
 
-    /// Update validators for an escrow
+    /// Decide a disputed milestone. Once a majority of the arbiters configured
+    /// when the dispute opened agree the funds are released, or the milestone
+    /// is rejected as if the validators had rejected it; ties favour the backers.
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
-    /// * `new_validators` - New list of validator addresses
-    pub fn update_validators(
+    /// * `milestone_id` - Milestone identifier
+    /// * `arbiter` - Arbiter casting the decision
+    /// * `approve` - True to release the funds, false to reject the milestone
+    pub fn resolve_dispute(
         env: Env,
         project_id: u64,
-        new_validators: Vec<Address>,
+        milestone_id: u64,
+        arbiter: Address,
+        approve: bool,
     ) -> Result<(), Error> {
-        // Get admin
-        let admin = get_admin(&env)?;
-        admin.require_auth();
-
-        // Validate new validators
-        if (new_validators.len() as u32) < MIN_VALIDATORS {
-            return Err(Error::InvalidInput);
-        }
+        arbiter.require_auth();
 
-        // Get escrow
         let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
 
-        // Update validators
-        escrow.validators = new_validators.clone();
-
-        // Store updated escrow
-        set_escrow(&env, project_id, &escrow);
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
 
-        // Emit event
-        env.events()
-            .publish((VALIDATORS_UPDATED,), (project_id, new_validators));
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
 
-        Ok(())
-    }
+        if milestone.status != MilestoneStatus::Disputed {
+            return Err(Error::InvalidMilestoneStatus);
+        }
 
-    /// Pause the contract — halts all critical operations instantly
-    ///
-    /// # Arguments
-    /// * `admin` - Must be the platform admin
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        let stored_admin = get_admin(&env)?;
-        if stored_admin != admin {
+        // Arbiters are those configured when the dispute was opened
+        let mut dispute = get_dispute(&env, project_id, milestone_id).ok_or(Error::NotFound)?;
+        let arbiters = dispute.arbiters.clone();
+        if !arbiters.contains(&arbiter) {
             return Err(Error::Unauthorized);
         }
-        admin.require_auth();
 
-        let now = env.ledger().timestamp();
-        let state = PauseState {
-            paused: true,
-            paused_at: now,
-            resume_not_before: now + RESUME_TIME_DELAY,
-        };
+        if has_arbiter_vote(&env, &milestone, &arbiter) {
+            return Err(Error::AlreadyVoted);
+        }
+        set_arbiter_vote(&env, &milestone, &arbiter);
 
-        set_pause_state(&env, &state);
+        if approve {
+            dispute.approvals += 1;
+        } else {
+            dispute.rejections += 1;
+        }
 
-        env.events().publish((CONTRACT_PAUSED,), (admin, now));
+        let released = dispute.approvals * 2 > arbiters.len();
+        if released || dispute.rejections * 2 >= arbiters.len() {
+            clear_dispute(&env, project_id, milestone_id);
+            env.events()
+                .publish((DISPUTE_RESOLVED,), (project_id, milestone_id, released));
+
+            // Validators who voted against the final outcome lose part of their bond
+            for validator in escrow.validators.iter() {
+                if get_validator_vote(&env, project_id, milestone_id, &validator) == Some(!released)
+                {
+                    slash_validator(&env, &escrow, &validator)?;
+                }
+            }
+
+            if released {
+                approve_milestone(&env, &mut escrow, &mut milestone)?;
+            } else {
+                reject_milestone(&env, &mut milestone)?;
+            }
+        } else {
+            set_dispute(&env, project_id, milestone_id, &dispute);
+        }
 
         Ok(())
     }
 
-    /// Resume the contract — only allowed after the time delay has passed
+    /// Get the dispute of a milestone approval, if any
     ///
     /// # Arguments
-    /// * `admin` - Must be the platform admin
-    pub fn resume(env: Env, admin: Address) -> Result<(), Error> {
-        let stored_admin = get_admin(&env)?;
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        admin.require_auth();
-
-        let state = get_pause_state(&env);
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_dispute(env: Env, project_id: u64, milestone_id: u64) -> Option<MilestoneDispute> {
+        get_dispute(&env, project_id, milestone_id)
+    }
 
-        let now = env.ledger().timestamp();
-        if now < state.resume_not_before {
+    /// Release the funds of a milestone whose challenge window closed without
+    /// a dispute. Callable by anyone.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn finalize_release(env: Env, project_id: u64, milestone_id: u64) -> Result<(), Error> {
+        let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        if milestone.status != MilestoneStatus::PendingRelease {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        if env.ledger().timestamp() < milestone.challenge_ends_at {
+            return Err(Error::InvalidInput); // Challenge window still open
+        }
+
+        clear_dispute(&env, project_id, milestone_id);
+        approve_milestone(&env, &mut escrow, &mut milestone)?;
+
+        Ok(())
+    }
+
+    /// Release the tranches of an approved milestone that have vested since
+    /// approval. Callable by anyone.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    ///
+    /// # Returns
+    /// The amount released to the creator
+    pub fn release_tranches(env: Env, project_id: u64, milestone_id: u64) -> Result<Amount, Error> {
+        let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if milestone.status != MilestoneStatus::Approved {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        let released = release_vested_tranches(&env, &mut escrow, &mut milestone)?;
+        if released == 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        Ok(released)
+    }
+
+    /// Get the release schedule of a milestone. Vesting times are zero until
+    /// the milestone is approved.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_tranche_schedule(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<Vec<TrancheStatus>, Error> {
+        let milestone = get_milestone(&env, project_id, milestone_id)?;
+        let approved = milestone.status == MilestoneStatus::Approved;
+
+        let mut schedule = Vec::new(&env);
+        for (index, tranche) in milestone.tranches.iter().enumerate() {
+            let index = index as u32;
+            schedule.push_back(TrancheStatus {
+                amount: tranche_amount(&milestone, index),
+                vests_at: if approved {
+                    milestone.approved_at.saturating_add(tranche.delay)
+                } else {
+                    0
+                },
+                released: index < milestone.tranches_released,
+            });
+        }
+
+        Ok(schedule)
+    }
+
+    /// Set how submitted milestones resolve when their voting window closes
+    /// without a decision. Admin only.
+    ///
+    /// # Arguments
+    /// * `outcome` - `MilestoneStatus::Approved` or `MilestoneStatus::Rejected`
+    pub fn set_expiry_outcome(env: Env, outcome: MilestoneStatus) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        if outcome != MilestoneStatus::Approved && outcome != MilestoneStatus::Rejected {
+            return Err(Error::InvalidInput);
+        }
+
+        set_expiry_outcome(&env, outcome);
+
+        Ok(())
+    }
+
+    /// Get how submitted milestones resolve when their voting window closes
+    /// without a decision
+    pub fn get_expiry_outcome(env: Env) -> MilestoneStatus {
+        get_expiry_outcome(&env)
+    }
+
+    /// Set the time after which anyone can mark the escrow abandoned if it
+    /// still holds unreleased funds. Admin only; the deadline must lie in
+    /// the future.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `deadline` - Abandonment deadline (Unix timestamp)
+    pub fn set_abandonment_deadline(env: Env, project_id: u64, deadline: u64) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        get_escrow(&env, project_id)?;
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        if deadline <= env.ledger().timestamp() {
+            return Err(Error::InvalidInput);
+        }
+
+        set_abandonment_deadline(&env, project_id, deadline);
+
+        Ok(())
+    }
+
+    /// Get the abandonment deadline of an escrow, if any
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_abandonment_deadline(env: Env, project_id: u64) -> Option<u64> {
+        get_abandonment_deadline(&env, project_id)
+    }
+
+    /// Vote to abandon an escrow. The escrow is abandoned once the votes of
+    /// current validators reach the escrow's approval threshold.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `voter` - Address of the validator
+    pub fn vote_abandon(env: Env, project_id: u64, voter: Address) -> Result<(), Error> {
+        voter.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+        validation::validate_validator(&escrow, &voter)?;
+
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        if has_abandon_vote(&env, project_id, &voter) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        set_abandon_vote(&env, project_id, &voter);
+
+        let votes = escrow
+            .validators
+            .iter()
+            .filter(|validator| has_abandon_vote(&env, project_id, validator))
+            .count() as u32;
+        // Round up so a fractional share of validators never lowers the bar
+        let required_votes = (escrow.validators.len() * escrow.approval_threshold).div_ceil(10000);
+
+        if votes >= required_votes {
+            abandon_escrow(&env, &escrow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Mark an escrow abandoned once its abandonment deadline has passed
+    /// with funds still unreleased. Callable by anyone.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn mark_abandoned(env: Env, project_id: u64) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+
+        if is_abandoned(&env, project_id) {
+            return Err(Error::ProjectNotActive);
+        }
+
+        let deadline = get_abandonment_deadline(&env, project_id).ok_or(Error::InvalidInput)?;
+        if env.ledger().timestamp() <= deadline {
+            return Err(Error::InvalidInput); // Deadline hasn't passed yet
+        }
+
+        if unreleased_balance(&env, &escrow)? <= 0 {
+            return Err(Error::InvalidInput); // Nothing left to refund
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        abandon_escrow(&env, &escrow)?;
+
+        Ok(())
+    }
+
+    /// Check if an escrow has been abandoned
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn is_abandoned(env: Env, project_id: u64) -> bool {
+        is_abandoned(&env, project_id)
+    }
+
+    /// Claim a backer's pro-rata share of the refundable funds. The amount of
+    /// a failed milestone is split across the deposits made before it failed;
+    /// funds left unreleased when the escrow was abandoned are split across
+    /// all deposits.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `backer` - Address that deposited into the escrow
+    pub fn claim_refund(env: Env, project_id: u64, backer: Address) -> Result<Amount, Error> {
+        backer.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let amount = get_claimable_refund(&env, &escrow, &backer)?;
+        if amount <= 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        let claimed = get_refund_claimed(&env, project_id, &backer)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        set_refund_claimed(&env, project_id, &backer, claimed);
+        add_refunded_amount(&env, project_id, amount)?;
+        let recorded = get_recorded_balance(&env, &escrow.token) - amount;
+        set_recorded_balance(&env, &escrow.token, recorded);
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &backer, &amount);
+
+        env.events()
+            .publish((REFUND_ISSUED,), (project_id, backer, amount));
+
+        Ok(amount)
+    }
+
+    /// Get the refund a backer can claim right now
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `backer` - Address that deposited into the escrow
+    pub fn get_claimable_refund(
+        env: Env,
+        project_id: u64,
+        backer: Address,
+    ) -> Result<Amount, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        get_claimable_refund(&env, &escrow, &backer)
+    }
+
+    /// Get the total refunded to backers of an escrow
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_refunded_amount(env: Env, project_id: u64) -> Amount {
+        get_refunded_amount(&env, project_id)
+    }
+
+    /// Get escrow information
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_escrow(env: Env, project_id: u64) -> Result<EscrowInfo, Error> {
+        get_escrow(&env, project_id)
+    }
+
+    /// Get milestone information
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_milestone(env: Env, project_id: u64, milestone_id: u64) -> Result<Milestone, Error> {
+        get_milestone(&env, project_id, milestone_id)
+    }
+
+    /// Get the decided review rounds of a milestone, oldest first
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_milestone_history(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Vec<MilestoneReview> {
+        get_milestone_history(&env, project_id, milestone_id)
+    }
+
+    /// Get the amount of failed milestones set aside for refunds to backers
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_refundable_amount(env: Env, project_id: u64) -> Amount {
+        get_refundable_amount(&env, project_id)
+    }
+
+    /// Get the total amount allocated to milestones
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_total_milestone_amount(env: Env, project_id: u64) -> Result<Amount, Error> {
+        get_total_milestone_amount(&env, project_id)
+    }
+
+    /// Get the balance of an escrow not yet allocated to milestones. Amounts
+    /// of expired milestones become available again; an abandoned escrow has
+    /// nothing left to allocate.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_available_balance(env: Env, project_id: u64) -> Result<Amount, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        if is_abandoned(&env, project_id) {
+            return Ok(0);
+        }
+        Ok(escrow.total_deposited - get_total_milestone_amount(&env, project_id)?)
+    }
+
+    /// Update validators for an escrow. Admin only; rejected while a
+    /// milestone is awaiting votes.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `new_validators` - New list of validator addresses
+    pub fn update_validators(
+        env: Env,
+        project_id: u64,
+        new_validators: Vec<Address>,
+    ) -> Result<(), Error> {
+        // Get admin
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        // Get escrow
+        let mut escrow = get_escrow(&env, project_id)?;
+
+        // Validate new validators
+        validation::validate_escrow_config(&new_validators, escrow.approval_threshold)?;
+
+        set_validators(&env, &mut escrow, new_validators)
+    }
+
+    /// Propose adding or removing a validator. Creator only.
+    ///
+    /// The change applies once current validators reach the approval
+    /// threshold or the governance contract approves it.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `change` - Validator to add or remove
+    pub fn propose_validator_change(
+        env: Env,
+        project_id: u64,
+        change: ValidatorChange,
+    ) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        open_validator_proposal(&env, &escrow, change)
+    }
+
+    /// Propose adding a candidate that bonded at least the minimum bond to
+    /// the validators. The proposal is approved like a creator's proposal.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `candidate` - Bonded address asking to join
+    pub fn request_validator_seat(
+        env: Env,
+        project_id: u64,
+        candidate: Address,
+    ) -> Result<(), Error> {
+        candidate.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+        let min_bond = get_bond_config(&env).min_bond;
+        if min_bond <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        if get_validator_stake(&env, project_id, &candidate) < min_bond {
+            return Err(Error::InsufficientFunds);
+        }
+
+        open_validator_proposal(&env, &escrow, ValidatorChange::Add(candidate))
+    }
+
+    /// Withdraw the pending validator set change. Creator only.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn cancel_validator_change(env: Env, project_id: u64) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        if get_validator_proposal(&env, project_id).is_none() {
+            return Err(Error::NotFound);
+        }
+        clear_validator_proposal(&env, project_id);
+
+        Ok(())
+    }
+
+    /// Approve the pending validator set change as a current validator. The
+    /// change applies once approvals reach the escrow's approval threshold.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator approving the change
+    pub fn approve_validator_change(
+        env: Env,
+        project_id: u64,
+        validator: Address,
+    ) -> Result<(), Error> {
+        validator.require_auth();
+
+        let mut escrow = get_escrow(&env, project_id)?;
+        validation::validate_validator(&escrow, &validator)?;
+
+        let mut proposal = get_validator_proposal(&env, project_id).ok_or(Error::NotFound)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if proposal.approvers.contains(&validator) {
+            return Err(Error::AlreadyVoted);
+        }
+        proposal.approvers.push_back(validator);
+
+        // Approvals of validators removed since the proposal opened no longer count
+        let approvals = proposal
+            .approvers
+            .iter()
+            .filter(|approver| escrow.validators.contains(approver))
+            .count() as u32;
+        let required_approvals =
+            (escrow.validators.len() * escrow.approval_threshold).div_ceil(10000);
+        if approvals >= required_approvals {
+            apply_validator_proposal(&env, &mut escrow, &proposal)?;
+        } else {
+            set_validator_proposal(&env, project_id, &proposal);
+        }
+
+        Ok(())
+    }
+
+    /// Approve the pending validator set change on behalf of governance.
+    /// Requires the governance contract's authorization.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn approve_change_by_governance(env: Env, project_id: u64) -> Result<(), Error> {
+        let governance = get_governance_contract(&env).ok_or(Error::NotInitialized)?;
+        governance.require_auth();
+
+        let mut escrow = get_escrow(&env, project_id)?;
+        let proposal = get_validator_proposal(&env, project_id).ok_or(Error::NotFound)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        apply_validator_proposal(&env, &mut escrow, &proposal)
+    }
+
+    /// Get the pending validator set change of an escrow, if any
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_validator_proposal(env: Env, project_id: u64) -> Option<ValidatorProposal> {
+        get_validator_proposal(&env, project_id)
+    }
+
+    /// Set the governance contract allowed to approve validator set changes. Admin only.
+    ///
+    /// # Arguments
+    /// * `governance_contract` - Address of the governance contract
+    pub fn set_governance_contract(env: Env, governance_contract: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        set_governance_contract(&env, &governance_contract);
+
+        Ok(())
+    }
+
+    /// Set the ReputationContract read for reputation-weighted voting. Admin only.
+    ///
+    /// # Arguments
+    /// * `reputation_contract` - Address of the ReputationContract
+    pub fn set_reputation_contract(env: Env, reputation_contract: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        set_reputation_contract(&env, &reputation_contract);
+
+        Ok(())
+    }
+
+    /// Choose how validator votes of an escrow are weighted. Admin only;
+    /// rejected while a milestone is awaiting votes. Weights are snapshotted
+    /// on each submission, so a change applies from the next submission on.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `weighting` - Equal, stake or reputation weighting
+    pub fn set_vote_weighting(
+        env: Env,
+        project_id: u64,
+        weighting: VoteWeighting,
+    ) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        get_escrow(&env, project_id)?;
+        if has_submitted_milestone(&env, project_id)? {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        if weighting == VoteWeighting::Reputation && get_reputation_contract(&env).is_none() {
+            return Err(Error::InvalidInput); // No ReputationContract to read scores from
+        }
+
+        set_vote_weighting(&env, project_id, weighting);
+
+        Ok(())
+    }
+
+    /// Get how validator votes of an escrow are weighted
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_vote_weighting(env: Env, project_id: u64) -> VoteWeighting {
+        get_vote_weighting(&env, project_id)
+    }
+
+    /// Lock escrow tokens as a validator's bond. Validators need at least the
+    /// configured minimum bond to review submissions, and the bond weights
+    /// their votes when the escrow uses stake weighting. While a minimum bond
+    /// is configured anyone may bond and then ask to join with
+    /// `request_validator_seat`.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator or candidate locking the stake
+    /// * `amount` - Amount to lock
+    pub fn lock_stake(
+        env: Env,
+        project_id: u64,
+        validator: Address,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        validator.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+        if get_bond_config(&env).min_bond <= 0 {
+            validation::validate_validator(&escrow, &validator)?;
+        }
+
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let stake = get_validator_stake(&env, project_id, &validator)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&validator, &env.current_contract_address(), &amount);
+
+        set_validator_stake(&env, project_id, &validator, stake);
+        let recorded = get_recorded_balance(&env, &escrow.token) + amount;
+        set_recorded_balance(&env, &escrow.token, recorded);
+
+        Ok(())
+    }
+
+    /// Start unbonding stake a validator locked in an escrow. The amount stays
+    /// slashable until the unbonding period passes and it is withdrawn with
+    /// `withdraw_stake`. Votes already cast keep the weight snapshotted at
+    /// submission.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator unbonding the stake
+    /// * `amount` - Amount to unbond
+    pub fn unlock_stake(
+        env: Env,
+        project_id: u64,
+        validator: Address,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        validator.require_auth();
+
+        get_escrow(&env, project_id)?;
+        let stake = get_validator_stake(&env, project_id, &validator);
+
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if amount > stake {
+            return Err(Error::InsufficientFunds);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut unbonding = get_unbonding(&env, project_id, &validator);
+        unbonding.amount = unbonding
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        unbonding.available_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(get_bond_config(&env).unbonding_period);
+
+        set_validator_stake(&env, project_id, &validator, stake - amount);
+        set_unbonding(&env, project_id, &validator, &unbonding);
+
+        Ok(())
+    }
+
+    /// Withdraw unbonded stake once the unbonding period has passed
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator withdrawing the stake
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    pub fn withdraw_stake(env: Env, project_id: u64, validator: Address) -> Result<Amount, Error> {
+        validator.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+        let unbonding = get_unbonding(&env, project_id, &validator);
+
+        if unbonding.amount <= 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        if env.ledger().timestamp() < unbonding.available_at {
+            return Err(Error::WithdrawalLocked);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        set_unbonding(
+            &env,
+            project_id,
+            &validator,
+            &Unbonding {
+                amount: 0,
+                available_at: 0,
+            },
+        );
+        let recorded = get_recorded_balance(&env, &escrow.token) - unbonding.amount;
+        set_recorded_balance(&env, &escrow.token, recorded);
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &validator,
+            &unbonding.amount,
+        );
+
+        Ok(unbonding.amount)
+    }
+
+    /// Get the stake a validator is unbonding from an escrow
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    pub fn get_unbonding(env: Env, project_id: u64, validator: Address) -> Unbonding {
+        get_unbonding(&env, project_id, &validator)
+    }
+
+    /// Set the bonding, reward and slashing rules for validators. Admin only.
+    ///
+    /// # Arguments
+    /// * `config` - Minimum bond, reward and slash shares, missed vote limit
+    ///   and unbonding period
+    pub fn set_bond_config(env: Env, config: ValidatorBondConfig) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        validation::validate_bond_config(&config)?;
+        set_bond_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the bonding, reward and slashing rules for validators
+    pub fn get_bond_config(env: Env) -> ValidatorBondConfig {
+        get_bond_config(&env)
+    }
+
+    /// Claim the release fee share a validator earned for voting
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator claiming the rewards
+    ///
+    /// # Returns
+    /// The amount claimed
+    pub fn claim_validator_rewards(
+        env: Env,
+        project_id: u64,
+        validator: Address,
+    ) -> Result<Amount, Error> {
+        validator.require_auth();
+
+        let escrow = get_escrow(&env, project_id)?;
+        let reward = get_validator_reward(&env, project_id, &validator);
+
+        if reward <= 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        set_validator_reward(&env, project_id, &validator, 0);
+        let recorded = get_recorded_balance(&env, &escrow.token) - reward;
+        set_recorded_balance(&env, &escrow.token, recorded);
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &validator, &reward);
+
+        Ok(reward)
+    }
+
+    /// Get the rewards a validator can claim from an escrow
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    pub fn get_validator_reward(env: Env, project_id: u64, validator: Address) -> Amount {
+        get_validator_reward(&env, project_id, &validator)
+    }
+
+    /// Get how many expired votes a validator has missed since their last slash
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    pub fn get_missed_votes(env: Env, project_id: u64, validator: Address) -> u32 {
+        get_missed_votes(&env, project_id, &validator)
+    }
+
+    /// Get the stake a validator locked in an escrow
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    pub fn get_validator_stake(env: Env, project_id: u64, validator: Address) -> Amount {
+        get_validator_stake(&env, project_id, &validator)
+    }
+
+    /// Get the validator weights snapshotted for the latest submission of a milestone
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_vote_weights(env: Env, project_id: u64, milestone_id: u64) -> Map<Address, Amount> {
+        get_vote_weights(&env, project_id, milestone_id)
+    }
+
+    /// Pause the contract — halts all critical operations instantly
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        let now = env.ledger().timestamp();
+        let state = PauseState {
+            paused: true,
+            paused_at: now,
+            resume_not_before: now + RESUME_TIME_DELAY,
+        };
+
+        set_pause_state(&env, &state);
+
+        env.events().publish((CONTRACT_PAUSED,), (admin, now));
+
+        Ok(())
+    }
+
+    /// Resume the contract — only allowed after the time delay has passed
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    pub fn resume(env: Env, admin: Address) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        let state = get_pause_state(&env);
+
+        let now = env.ledger().timestamp();
+        if now < state.resume_not_before {
             return Err(Error::ResumeTooEarly);
         }
 
@@ -543,22 +1728,612 @@ impl EscrowContract {
     }
 }
 
+/// Helper function to store a new escrow and its milestone counter
+fn create_escrow(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    token: Address,
+    validators: Vec<Address>,
+    approval_threshold: u32,
+) {
+    let escrow = EscrowInfo {
+        project_id,
+        creator: creator.clone(),
+        token: token.clone(),
+        total_deposited: 0,
+        released_amount: 0,
+        validators,
+        approval_threshold,
+    };
+
+    set_escrow(env, project_id, &escrow);
+    set_milestone_counter(env, project_id, 0);
+
+    env.events()
+        .publish((ESCROW_INITIALIZED,), (project_id, creator, token));
+}
+
+/// Helper function to check whether the voting window of a submitted milestone has closed
+fn voting_closed(env: &Env, milestone: &Milestone) -> bool {
+    milestone.voting_window.is_some_and(|window| {
+        env.ledger().timestamp() > milestone.submitted_at.saturating_add(window)
+    })
+}
+
+/// Helper function to approve a milestone and release the tranches that vest
+/// immediately to the creator
+fn approve_milestone(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    milestone: &mut Milestone,
+) -> Result<(), Error> {
+    milestone.status = MilestoneStatus::Approved;
+    milestone.approved_at = env.ledger().timestamp();
+    record_review(env, milestone);
+
+    // Emit approval event
+    env.events().publish(
+        (MILESTONE_APPROVED,),
+        (milestone.project_id, milestone.id, milestone.approval_count),
+    );
+
+    release_vested_tranches(env, escrow, milestone)?;
+
+    Ok(())
+}
+
+/// Helper function to add `stake` to the dispute of a milestone awaiting
+/// release, freezing the milestone once the dispute threshold is met.
+/// Returns when the challenge window closes.
+fn back_dispute(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    backer: &Address,
+    stake: Amount,
+) -> Result<u64, Error> {
+    let escrow = get_escrow(env, project_id)?;
+    let mut milestone = get_milestone(env, project_id, milestone_id)?;
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if is_abandoned(env, project_id) {
+        return Err(Error::ProjectNotActive);
+    }
+
+    if milestone.status != MilestoneStatus::PendingRelease {
+        return Err(Error::InvalidMilestoneStatus);
+    }
+
+    if env.ledger().timestamp() >= milestone.challenge_ends_at {
+        return Err(Error::DeadlinePassed);
+    }
+
+    if has_dispute_vote(env, &milestone, backer) {
+        return Err(Error::AlreadyVoted);
+    }
+    set_dispute_vote(env, &milestone, backer);
+
+    let mut dispute = get_dispute(env, project_id, milestone_id).unwrap_or(MilestoneDispute {
+        stake: 0,
+        arbiters: get_challenge_config(env).arbiters,
+        approvals: 0,
+        rejections: 0,
+    });
+    dispute.stake = dispute
+        .stake
+        .checked_add(stake)
+        .ok_or(Error::InvalidInput)?;
+    set_dispute(env, project_id, milestone_id, &dispute);
+
+    let threshold = get_challenge_config(env).dispute_threshold as Amount;
+    let required = escrow
+        .total_deposited
+        .checked_mul(threshold)
+        .ok_or(Error::InvalidInput)?;
+    if dispute
+        .stake
+        .checked_mul(10000)
+        .ok_or(Error::InvalidInput)?
+        >= required
+    {
+        milestone.status = MilestoneStatus::Disputed;
+        set_milestone(env, project_id, milestone_id, &milestone);
+
+        env.events().publish(
+            (MILESTONE_DISPUTED,),
+            (project_id, milestone_id, dispute.stake),
+        );
+    }
+
+    Ok(milestone.challenge_ends_at)
+}
+
+/// Helper function to accept a milestone the validators approved: funds are
+/// released right away unless a challenge window is configured, in which case
+/// they are held until `finalize_release`
+fn accept_milestone(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    milestone: &mut Milestone,
+) -> Result<(), Error> {
+    let period = get_challenge_config(env).period;
+    if period == 0 {
+        return approve_milestone(env, escrow, milestone);
+    }
+
+    milestone.status = MilestoneStatus::PendingRelease;
+    milestone.challenge_ends_at = env.ledger().timestamp().saturating_add(period);
+    set_milestone(env, milestone.project_id, milestone.id, milestone);
+
+    env.events().publish(
+        (RELEASE_PENDING,),
+        (
+            milestone.project_id,
+            milestone.id,
+            milestone.challenge_ends_at,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Helper function to put a validator set change up for approval
+fn open_validator_proposal(
+    env: &Env,
+    escrow: &EscrowInfo,
+    change: ValidatorChange,
+) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if get_validator_proposal(env, escrow.project_id).is_some() {
+        return Err(Error::InvalidInput); // A change is already pending
+    }
+
+    validation::apply_validator_change(&escrow.validators, &change)?;
+
+    let proposal = ValidatorProposal {
+        id: next_validator_proposal_id(env, escrow.project_id),
+        change: change.clone(),
+        approvers: Vec::new(env),
+        proposed_at: env.ledger().timestamp(),
+    };
+    set_validator_proposal(env, escrow.project_id, &proposal);
+
+    env.events().publish(
+        (VALIDATOR_CHANGE_PROPOSED,),
+        (escrow.project_id, proposal.id, change),
+    );
+
+    Ok(())
+}
+
+/// Helper function to apply an approved validator set change
+fn apply_validator_proposal(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    proposal: &ValidatorProposal,
+) -> Result<(), Error> {
+    let validators = validation::apply_validator_change(&escrow.validators, &proposal.change)?;
+    clear_validator_proposal(env, escrow.project_id);
+
+    set_validators(env, escrow, validators)
+}
+
+/// Helper function to replace the validators of an escrow. Rejected while a
+/// milestone is awaiting votes, so a vote never changes hands midway.
+fn set_validators(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    validators: Vec<Address>,
+) -> Result<(), Error> {
+    if has_submitted_milestone(env, escrow.project_id)? {
+        return Err(Error::InvalidMilestoneStatus);
+    }
+
+    // Update validators
+    escrow.validators = validators.clone();
+
+    // Store updated escrow
+    set_escrow(env, escrow.project_id, escrow);
+
+    // Emit event
+    env.events()
+        .publish((VALIDATORS_UPDATED,), (escrow.project_id, validators));
+
+    Ok(())
+}
+
+/// Helper function to snapshot the weight of every bonded validator for a new
+/// submission. Falls back to equal weights when no validator has any stake
+/// or reputation.
+fn snapshot_vote_weights(
+    env: &Env,
+    escrow: &EscrowInfo,
+    milestone: &mut Milestone,
+) -> Result<(), Error> {
+    let weighting = get_vote_weighting(env, escrow.project_id);
+    let min_bond = get_bond_config(env).min_bond;
+    let mut weights = Map::new(env);
+    let mut total: Amount = 0;
+
+    for validator in escrow.validators.iter() {
+        // Validators short of the minimum bond sit this submission out
+        if min_bond > 0 && get_validator_stake(env, escrow.project_id, &validator) < min_bond {
+            continue;
+        }
+
+        let weight = match weighting {
+            VoteWeighting::Equal => 1,
+            VoteWeighting::Stake => get_validator_stake(env, escrow.project_id, &validator),
+            VoteWeighting::Reputation => reputation_score(env, &validator),
+        };
+        total = total.checked_add(weight).ok_or(Error::InvalidInput)?;
+        weights.set(validator, weight);
+    }
+
+    if weights.is_empty() {
+        return Err(Error::InvalidInput); // No bonded validators to review the submission
+    }
+
+    if total == 0 {
+        for validator in weights.keys().iter() {
+            weights.set(validator, 1);
+        }
+        total = weights.len() as Amount;
+    }
+
+    milestone.total_weight = total;
+    milestone.approval_weight = 0;
+    milestone.rejection_weight = 0;
+    set_vote_weights(env, escrow.project_id, milestone.id, &weights);
+
+    Ok(())
+}
+
+/// Helper function to credit the validators who voted on a milestone with the
+/// configured share of a release. Returns the amount withheld from the creator.
+fn reward_validators(
+    env: &Env,
+    escrow: &EscrowInfo,
+    milestone: &Milestone,
+    released: Amount,
+) -> Result<Amount, Error> {
+    let reward_share = get_bond_config(env).reward_share;
+    if reward_share == 0 {
+        return Ok(0);
+    }
+
+    let mut voters = Vec::new(env);
+    for validator in escrow.validators.iter() {
+        if get_validator_vote(env, escrow.project_id, milestone.id, &validator).is_some() {
+            voters.push_back(validator);
+        }
+    }
+    if voters.is_empty() {
+        return Ok(0);
+    }
+
+    let pool = released
+        .checked_mul(reward_share as Amount)
+        .ok_or(Error::InvalidInput)?
+        / 10000;
+    let per_voter = pool / voters.len() as Amount;
+    if per_voter == 0 {
+        return Ok(0);
+    }
+
+    for voter in voters.iter() {
+        add_validator_reward(env, escrow.project_id, &voter, per_voter)?;
+        env.events().publish(
+            (VALIDATOR_REWARDED,),
+            (escrow.project_id, milestone.id, voter, per_voter),
+        );
+    }
+
+    // Rewards stay in the contract until claimed
+    let reward = per_voter * voters.len() as Amount;
+    let recorded = get_recorded_balance(env, &escrow.token) + reward;
+    set_recorded_balance(env, &escrow.token, recorded);
+
+    Ok(reward)
+}
+
+/// Helper function to count a missed vote against every validator that was
+/// eligible for an expired submission but never voted, slashing those who
+/// reach the configured limit
+fn record_missed_votes(env: &Env, escrow: &EscrowInfo, milestone: &Milestone) -> Result<(), Error> {
+    let max_missed = get_bond_config(env).max_missed_votes;
+    if max_missed == 0 {
+        return Ok(());
+    }
+
+    let weights = get_vote_weights(env, escrow.project_id, milestone.id);
+    for validator in weights.keys().iter() {
+        if get_validator_vote(env, escrow.project_id, milestone.id, &validator).is_some() {
+            continue;
+        }
+
+        let missed = get_missed_votes(env, escrow.project_id, &validator) + 1;
+        if missed >= max_missed {
+            slash_validator(env, escrow, &validator)?;
+            set_missed_votes(env, escrow.project_id, &validator, 0);
+        } else {
+            set_missed_votes(env, escrow.project_id, &validator, missed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function to take the configured share of a validator's bond,
+/// including stake still unbonding, and send it to the platform admin
+fn slash_validator(env: &Env, escrow: &EscrowInfo, validator: &Address) -> Result<(), Error> {
+    let slash_share = get_bond_config(env).slash_share;
+    let stake = get_validator_stake(env, escrow.project_id, validator);
+    let mut unbonding = get_unbonding(env, escrow.project_id, validator);
+
+    let penalty = stake
+        .checked_add(unbonding.amount)
+        .and_then(|bond| bond.checked_mul(slash_share as Amount))
+        .ok_or(Error::InvalidInput)?
+        / 10000;
+    if penalty == 0 {
+        return Ok(());
+    }
+
+    // Bonded stake is slashed before stake that is unbonding
+    let from_stake = penalty.min(stake);
+    set_validator_stake(env, escrow.project_id, validator, stake - from_stake);
+    unbonding.amount -= penalty - from_stake;
+    set_unbonding(env, escrow.project_id, validator, &unbonding);
+
+    let recorded = get_recorded_balance(env, &escrow.token) - penalty;
+    set_recorded_balance(env, &escrow.token, recorded);
+
+    let admin = get_admin(env)?;
+    let token_client = TokenClient::new(env, &escrow.token);
+    token_client.transfer(&env.current_contract_address(), &admin, &penalty);
+
+    env.events().publish(
+        (VALIDATOR_SLASHED,),
+        (escrow.project_id, validator.clone(), penalty),
+    );
+
+    Ok(())
+}
+
+/// Helper function to read a validator's ReputationContract score
+fn reputation_score(env: &Env, validator: &Address) -> Amount {
+    let reputation_contract = match get_reputation_contract(env) {
+        Some(reputation_contract) => reputation_contract,
+        None => return 0,
+    };
+
+    // Unregistered validators have no score
+    let reputation_client = ReputationContractClient::new(env, &reputation_contract);
+    match reputation_client.try_get_profile(validator) {
+        Ok(Ok(profile)) => profile.score.max(0),
+        _ => 0,
+    }
+}
+
+/// Helper function to compute the amount of a milestone tranche; the last
+/// tranche takes the rounding remainder
+fn tranche_amount(milestone: &Milestone, index: u32) -> Amount {
+    if index + 1 == milestone.tranches.len() {
+        let mut earlier: Amount = 0;
+        for i in 0..index {
+            earlier += tranche_amount(milestone, i);
+        }
+        return milestone.amount - earlier;
+    }
+
+    let share = milestone
+        .tranches
+        .get(index)
+        .map_or(0, |tranche| tranche.share);
+    milestone.amount * share as Amount / 10000
+}
+
+/// Helper function to pay out every vested tranche of an approved milestone
+/// that has not been released yet. Returns the amount released.
+fn release_vested_tranches(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    milestone: &mut Milestone,
+) -> Result<Amount, Error> {
+    let now = env.ledger().timestamp();
+    let mut amount: Amount = 0;
+
+    while milestone.tranches_released < milestone.tranches.len() {
+        let tranche = milestone
+            .tranches
+            .get(milestone.tranches_released)
+            .ok_or(Error::InvalidInput)?;
+        if now < milestone.approved_at.saturating_add(tranche.delay) {
+            break;
+        }
+        amount += tranche_amount(milestone, milestone.tranches_released);
+        milestone.tranches_released += 1;
+    }
+
+    if amount > 0 {
+        // Release funds
+        release_milestone_funds(env, escrow, amount)?;
+        milestone.released_amount += amount;
+        let reward = reward_validators(env, escrow, milestone, amount)?;
+
+        // Perform token transfer to creator
+        let token_client = TokenClient::new(env, &escrow.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &escrow.creator,
+            &(amount - reward),
+        );
+
+        // Emit fund release event
+        env.events().publish(
+            (FUNDS_RELEASED,),
+            (milestone.project_id, milestone.id, amount),
+        );
+    }
+
+    // Store updated escrow and milestone
+    set_escrow(env, escrow.project_id, escrow);
+    set_milestone(env, milestone.project_id, milestone.id, milestone);
+
+    Ok(amount)
+}
+
+/// Helper function to compute the funds of an escrow that are neither released
+/// nor owed to the creator through a vesting tranche
+fn unreleased_balance(env: &Env, escrow: &EscrowInfo) -> Result<Amount, Error> {
+    let vesting = get_vesting_amount(env, escrow.project_id)?;
+    Ok(escrow.total_deposited - escrow.released_amount - vesting)
+}
+
+/// Helper function to reject a milestone; once out of resubmissions it fails
+/// and its amount goes back to the backers
+fn reject_milestone(env: &Env, milestone: &mut Milestone) -> Result<(), Error> {
+    milestone.status = MilestoneStatus::Rejected;
+    record_review(env, milestone);
+
+    // Emit rejection event
+    env.events().publish(
+        (MILESTONE_REJECTED,),
+        (
+            milestone.project_id,
+            milestone.id,
+            milestone.rejection_count,
+        ),
+    );
+
+    if milestone.resubmission_count >= get_max_resubmissions(env) {
+        milestone.status = MilestoneStatus::Failed;
+        add_refundable_amount(env, milestone.project_id, milestone.amount)?;
+        // Split across the deposits made so far; later deposits get no share
+        let escrow = get_escrow(env, milestone.project_id)?;
+        if escrow.total_deposited > 0 {
+            let rate = milestone
+                .amount
+                .checked_mul(REFUND_RATE_SCALE)
+                .ok_or(Error::InvalidInput)?
+                / escrow.total_deposited;
+            add_refund_rate(env, milestone.project_id, rate)?;
+        }
+        env.events().publish(
+            (MILESTONE_FAILED,),
+            (milestone.project_id, milestone.id, milestone.amount),
+        );
+    }
+    set_milestone(env, milestone.project_id, milestone.id, milestone);
+
+    Ok(())
+}
+
+/// Helper function to append the decided review round of a milestone to its history
+fn record_review(env: &Env, milestone: &Milestone) {
+    let review = MilestoneReview {
+        proof_hash: milestone.proof_hash.clone(),
+        status: milestone.status,
+        approval_count: milestone.approval_count,
+        rejection_count: milestone.rejection_count,
+        decided_at: env.ledger().timestamp(),
+    };
+    add_milestone_review(env, milestone.project_id, milestone.id, &review);
+}
+
+/// Helper function to record a deposit, excluding it from the refunds of
+/// milestones that failed before it was made
+fn record_deposit(
+    env: &Env,
+    project_id: u64,
+    source: &Address,
+    amount: Amount,
+) -> Result<(), Error> {
+    add_deposit_from_source(env, project_id, source, amount)?;
+    let debt = amount
+        .checked_mul(get_refund_rate(env, project_id))
+        .ok_or(Error::InvalidInput)?
+        / REFUND_RATE_SCALE;
+    add_refund_debt(env, project_id, source, debt)
+}
+
+/// Helper function to mark an escrow abandoned, snapshotting the funds
+/// refunds are split against. Amounts of failed milestones stay with the
+/// backers they were already owed to.
+fn abandon_escrow(env: &Env, escrow: &EscrowInfo) -> Result<(), Error> {
+    let snapshot = AbandonmentSnapshot {
+        total_deposited: escrow.total_deposited,
+        refundable: unreleased_balance(env, escrow)?
+            - get_refundable_amount(env, escrow.project_id),
+    };
+    set_abandonment_snapshot(env, escrow.project_id, &snapshot);
+    set_abandoned(env, escrow.project_id);
+
+    env.events().publish(
+        (ESCROW_ABANDONED,),
+        (escrow.project_id, snapshot.refundable),
+    );
+
+    Ok(())
+}
+
+/// Helper function to compute the refund a backer can still claim: their
+/// share of each failed milestone, by the deposits made before it failed,
+/// plus their share of the funds snapshotted when the escrow was abandoned,
+/// minus what they already claimed. Never more than the refund funds left.
+fn get_claimable_refund(env: &Env, escrow: &EscrowInfo, backer: &Address) -> Result<Amount, Error> {
+    let project_id = escrow.project_id;
+    let deposited = get_deposit_from_source(env, project_id, backer);
+    let mut share = deposited
+        .checked_mul(get_refund_rate(env, project_id))
+        .ok_or(Error::InvalidInput)?
+        / REFUND_RATE_SCALE
+        - get_refund_debt(env, project_id, backer);
+    let mut pool = get_refundable_amount(env, project_id);
+
+    if let Some(snapshot) = get_abandonment_snapshot(env, project_id) {
+        if snapshot.total_deposited > 0 {
+            share += deposited
+                .checked_mul(snapshot.refundable)
+                .ok_or(Error::InvalidInput)?
+                / snapshot.total_deposited;
+        }
+        pool += snapshot.refundable;
+    }
+
+    let claimed = get_refund_claimed(env, project_id, backer);
+    let left = pool - get_refunded_amount(env, project_id);
+
+    Ok((share - claimed).min(left).max(0))
+}
+
 /// Helper function to release milestone funds
 fn release_milestone_funds(
-    _env: &Env,
+    env: &Env,
     escrow: &mut EscrowInfo,
-    milestone: &Milestone,
+    amount: Amount,
 ) -> Result<(), Error> {
     // Verify funds are not released more than once
     let new_released = escrow
         .released_amount
-        .checked_add(milestone.amount)
+        .checked_add(amount)
         .ok_or(Error::InvalidInput)?;
 
-    if new_released > escrow.total_deposited {
+    let refunded = get_refunded_amount(env, escrow.project_id);
+    if new_released + refunded > escrow.total_deposited {
         return Err(Error::InsufficientEscrowBalance);
     }
 
     escrow.released_amount = new_released;
+    let recorded = get_recorded_balance(env, &escrow.token) - amount;
+    set_recorded_balance(env, &escrow.token, recorded);
     Ok(())
 }
@@ -1,6 +1,11 @@
 use shared::errors::Error;
-use shared::types::{Amount, EscrowInfo, Milestone, PauseState, PendingUpgrade};
-use soroban_sdk::{Address, Env};
+use shared::types::{
+    AbandonmentSnapshot, Amount, ChallengeConfig, EscrowInfo, Milestone, MilestoneDispute,
+    MilestoneReview, MilestoneStatus, PauseState, PendingUpgrade, Unbonding, ValidatorBondConfig,
+    ValidatorProposal, VoteWeighting,
+};
+use shared::{DEFAULT_DISPUTE_THRESHOLD, DEFAULT_MAX_RESUBMISSIONS};
+use soroban_sdk::{Address, Env, Map, Vec};
 
 /// Storage keys for escrow data structures
 const ESCROW_PREFIX: &str = "escrow";
@@ -8,6 +13,37 @@ const MILESTONE_PREFIX: &str = "milestone";
 const MILESTONE_COUNTER_PREFIX: &str = "m_counter";
 const VALIDATOR_VOTE_PREFIX: &str = "v_vote";
 const ADMIN_KEY: &str = "admin";
+const LAUNCH_CONTRACT_KEY: &str = "launch";
+const DEPOSIT_SOURCE_PREFIX: &str = "dep_src";
+const RECORDED_BALANCE_PREFIX: &str = "rec_bal";
+const MILESTONE_HISTORY_PREFIX: &str = "m_hist";
+const REFUNDABLE_PREFIX: &str = "refund";
+const MAX_RESUBMISSIONS_KEY: &str = "max_resub";
+const ABANDONED_PREFIX: &str = "abandoned";
+const ABANDON_DEADLINE_PREFIX: &str = "ab_dline";
+const ABANDON_VOTE_PREFIX: &str = "ab_vote";
+const ABANDON_SNAPSHOT_PREFIX: &str = "ab_snap";
+const REFUNDED_PREFIX: &str = "refunded";
+const REFUND_CLAIMED_PREFIX: &str = "ref_claim";
+const REFUND_RATE_PREFIX: &str = "ref_rate";
+const REFUND_DEBT_PREFIX: &str = "ref_debt";
+const EXPIRY_OUTCOME_KEY: &str = "exp_out";
+const CHALLENGE_CONFIG_KEY: &str = "challenge";
+const DISPUTE_PREFIX: &str = "dispute";
+const DISPUTE_VOTE_PREFIX: &str = "disp_vote";
+const ARBITER_VOTE_PREFIX: &str = "arb_vote";
+const REPUTATION_CONTRACT_KEY: &str = "reputation";
+const VOTE_WEIGHTING_PREFIX: &str = "weighting";
+const VALIDATOR_STAKE_PREFIX: &str = "v_stake";
+const VOTE_WEIGHTS_PREFIX: &str = "v_weights";
+const BOND_CONFIG_KEY: &str = "bond_cfg";
+const UNBONDING_PREFIX: &str = "unbonding";
+const VALIDATOR_REWARD_PREFIX: &str = "v_reward";
+const MISSED_VOTES_PREFIX: &str = "v_missed";
+const GOVERNANCE_CONTRACT_KEY: &str = "governance";
+const VALIDATOR_PROPOSAL_PREFIX: &str = "v_prop";
+const VALIDATOR_PROPOSAL_COUNTER_PREFIX: &str = "v_prop_n";
+const MILESTONE_VOTERS_PREFIX: &str = "m_voters";
 
 /// Store platform admin
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -27,6 +63,18 @@ pub fn has_admin(env: &Env) -> bool {
     env.storage().instance().has(&ADMIN_KEY)
 }
 
+/// Store the trusted ProjectLaunch contract address
+pub fn set_launch_contract(env: &Env, launch_contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&LAUNCH_CONTRACT_KEY, launch_contract);
+}
+
+/// Retrieve the trusted ProjectLaunch contract address, if configured
+pub fn get_launch_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&LAUNCH_CONTRACT_KEY)
+}
+
 /// Store escrow information
 pub fn set_escrow(env: &Env, project_id: u64, escrow: &EscrowInfo) {
     let key = (ESCROW_PREFIX, project_id);
@@ -78,18 +126,513 @@ pub fn get_milestone_counter(env: &Env, project_id: u64) -> Result<u64, Error> {
         .ok_or(Error::NotFound)
 }
 
-/// Record that a validator voted on a milestone
+/// Add to the running total deposited into an escrow by a given source
+pub fn add_deposit_from_source(
+    env: &Env,
+    project_id: u64,
+    source: &Address,
+    amount: Amount,
+) -> Result<(), Error> {
+    let key = (DEPOSIT_SOURCE_PREFIX, project_id, source);
+    let current: Amount = env.storage().persistent().get(&key).unwrap_or(0);
+    let updated = current.checked_add(amount).ok_or(Error::InvalidInput)?;
+    env.storage().persistent().set(&key, &updated);
+    Ok(())
+}
+
+/// Retrieve the total deposited into an escrow by a given source
+pub fn get_deposit_from_source(env: &Env, project_id: u64, source: &Address) -> Amount {
+    let key = (DEPOSIT_SOURCE_PREFIX, project_id, source);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the amount of a token held on behalf of all escrows
+pub fn set_recorded_balance(env: &Env, token: &Address, amount: Amount) {
+    let key = (RECORDED_BALANCE_PREFIX, token);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the amount of a token held on behalf of all escrows
+pub fn get_recorded_balance(env: &Env, token: &Address) -> Amount {
+    let key = (RECORDED_BALANCE_PREFIX, token);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store how many times a rejected milestone may be resubmitted
+pub fn set_max_resubmissions(env: &Env, max: u32) {
+    env.storage().instance().set(&MAX_RESUBMISSIONS_KEY, &max);
+}
+
+/// Retrieve how many times a rejected milestone may be resubmitted
+pub fn get_max_resubmissions(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&MAX_RESUBMISSIONS_KEY)
+        .unwrap_or(DEFAULT_MAX_RESUBMISSIONS)
+}
+
+/// Append a decided review round to a milestone's history
+pub fn add_milestone_review(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    review: &MilestoneReview,
+) {
+    let key = (MILESTONE_HISTORY_PREFIX, project_id, milestone_id);
+    let mut history = get_milestone_history(env, project_id, milestone_id);
+    history.push_back(review.clone());
+    env.storage().persistent().set(&key, &history);
+}
+
+/// Retrieve the decided review rounds of a milestone, oldest first
+pub fn get_milestone_history(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+) -> Vec<MilestoneReview> {
+    let key = (MILESTONE_HISTORY_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add to the amount of an escrow set aside for refunds to backers
+pub fn add_refundable_amount(env: &Env, project_id: u64, amount: Amount) -> Result<(), Error> {
+    let key = (REFUNDABLE_PREFIX, project_id);
+    let updated = get_refundable_amount(env, project_id)
+        .checked_add(amount)
+        .ok_or(Error::InvalidInput)?;
+    env.storage().persistent().set(&key, &updated);
+    Ok(())
+}
+
+/// Retrieve the amount of an escrow set aside for refunds to backers
+pub fn get_refundable_amount(env: &Env, project_id: u64) -> Amount {
+    let key = (REFUNDABLE_PREFIX, project_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the outcome of a submitted milestone whose voting window closes undecided
+pub fn set_expiry_outcome(env: &Env, outcome: MilestoneStatus) {
+    env.storage().instance().set(&EXPIRY_OUTCOME_KEY, &outcome);
+}
+
+/// Retrieve the outcome of a submitted milestone whose voting window closes
+/// undecided, rejecting by default
+pub fn get_expiry_outcome(env: &Env) -> MilestoneStatus {
+    env.storage()
+        .instance()
+        .get(&EXPIRY_OUTCOME_KEY)
+        .unwrap_or(MilestoneStatus::Rejected)
+}
+
+/// Store the challenge window applied to approved milestones
+pub fn set_challenge_config(env: &Env, config: &ChallengeConfig) {
+    env.storage().instance().set(&CHALLENGE_CONFIG_KEY, config);
+}
+
+/// Retrieve the challenge window, disabled unless the admin configured one
+pub fn get_challenge_config(env: &Env) -> ChallengeConfig {
+    env.storage()
+        .instance()
+        .get(&CHALLENGE_CONFIG_KEY)
+        .unwrap_or(ChallengeConfig {
+            period: 0,
+            dispute_threshold: DEFAULT_DISPUTE_THRESHOLD,
+            arbiters: Vec::new(env),
+        })
+}
+
+/// Store the dispute of a milestone approval
+pub fn set_dispute(env: &Env, project_id: u64, milestone_id: u64, dispute: &MilestoneDispute) {
+    let key = (DISPUTE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, dispute);
+}
+
+/// Retrieve the dispute of a milestone approval, if any
+pub fn get_dispute(env: &Env, project_id: u64, milestone_id: u64) -> Option<MilestoneDispute> {
+    let key = (DISPUTE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Remove the dispute of a milestone once its approval is settled
+pub fn clear_dispute(env: &Env, project_id: u64, milestone_id: u64) {
+    let key = (DISPUTE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Record that a backer supported the dispute of a milestone review round
+pub fn set_dispute_vote(env: &Env, milestone: &Milestone, backer: &Address) {
+    let key = (
+        DISPUTE_VOTE_PREFIX,
+        milestone.project_id,
+        milestone.id,
+        backer,
+    );
+    env.storage()
+        .persistent()
+        .set(&key, &milestone.resubmission_count);
+}
+
+/// Check if a backer supported the dispute of the current review round
+pub fn has_dispute_vote(env: &Env, milestone: &Milestone, backer: &Address) -> bool {
+    let key = (
+        DISPUTE_VOTE_PREFIX,
+        milestone.project_id,
+        milestone.id,
+        backer,
+    );
+    env.storage().persistent().get::<_, u32>(&key) == Some(milestone.resubmission_count)
+}
+
+/// Record that an arbiter decided the dispute of a milestone review round
+pub fn set_arbiter_vote(env: &Env, milestone: &Milestone, arbiter: &Address) {
+    let key = (
+        ARBITER_VOTE_PREFIX,
+        milestone.project_id,
+        milestone.id,
+        arbiter,
+    );
+    env.storage()
+        .persistent()
+        .set(&key, &milestone.resubmission_count);
+}
+
+/// Check if an arbiter decided the dispute of the current review round
+pub fn has_arbiter_vote(env: &Env, milestone: &Milestone, arbiter: &Address) -> bool {
+    let key = (
+        ARBITER_VOTE_PREFIX,
+        milestone.project_id,
+        milestone.id,
+        arbiter,
+    );
+    env.storage().persistent().get::<_, u32>(&key) == Some(milestone.resubmission_count)
+}
+
+/// Store the ReputationContract used for reputation-weighted voting
+pub fn set_reputation_contract(env: &Env, reputation_contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&REPUTATION_CONTRACT_KEY, reputation_contract);
+}
+
+/// Retrieve the ReputationContract used for reputation-weighted voting
+pub fn get_reputation_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&REPUTATION_CONTRACT_KEY)
+}
+
+/// Store how validator votes of an escrow are weighted
+pub fn set_vote_weighting(env: &Env, project_id: u64, weighting: VoteWeighting) {
+    let key = (VOTE_WEIGHTING_PREFIX, project_id);
+    env.storage().persistent().set(&key, &weighting);
+}
+
+/// Retrieve how validator votes of an escrow are weighted (equal by default)
+pub fn get_vote_weighting(env: &Env, project_id: u64) -> VoteWeighting {
+    let key = (VOTE_WEIGHTING_PREFIX, project_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(VoteWeighting::Equal)
+}
+
+/// Store the stake a validator locked in an escrow
+pub fn set_validator_stake(env: &Env, project_id: u64, validator: &Address, amount: Amount) {
+    let key = (VALIDATOR_STAKE_PREFIX, project_id, validator);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the stake a validator locked in an escrow
+pub fn get_validator_stake(env: &Env, project_id: u64, validator: &Address) -> Amount {
+    let key = (VALIDATOR_STAKE_PREFIX, project_id, validator);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the bonding, reward and slashing rules for validators
+pub fn set_bond_config(env: &Env, config: &ValidatorBondConfig) {
+    env.storage().instance().set(&BOND_CONFIG_KEY, config);
+}
+
+/// Retrieve the bonding rules for validators; bonds, rewards and slashing are
+/// disabled unless the admin configured them
+pub fn get_bond_config(env: &Env) -> ValidatorBondConfig {
+    env.storage()
+        .instance()
+        .get(&BOND_CONFIG_KEY)
+        .unwrap_or(ValidatorBondConfig {
+            min_bond: 0,
+            reward_share: 0,
+            slash_share: 0,
+            max_missed_votes: 0,
+            unbonding_period: 0,
+        })
+}
+
+/// Store the stake a validator is unbonding from an escrow
+pub fn set_unbonding(env: &Env, project_id: u64, validator: &Address, unbonding: &Unbonding) {
+    let key = (UNBONDING_PREFIX, project_id, validator);
+    env.storage().persistent().set(&key, unbonding);
+}
+
+/// Retrieve the stake a validator is unbonding from an escrow
+pub fn get_unbonding(env: &Env, project_id: u64, validator: &Address) -> Unbonding {
+    let key = (UNBONDING_PREFIX, project_id, validator);
+    env.storage().persistent().get(&key).unwrap_or(Unbonding {
+        amount: 0,
+        available_at: 0,
+    })
+}
+
+/// Add to the rewards a validator can claim from an escrow
+pub fn add_validator_reward(
+    env: &Env,
+    project_id: u64,
+    validator: &Address,
+    amount: Amount,
+) -> Result<(), Error> {
+    let updated = get_validator_reward(env, project_id, validator)
+        .checked_add(amount)
+        .ok_or(Error::InvalidInput)?;
+    set_validator_reward(env, project_id, validator, updated);
+    Ok(())
+}
+
+/// Store the rewards a validator can claim from an escrow
+pub fn set_validator_reward(env: &Env, project_id: u64, validator: &Address, amount: Amount) {
+    let key = (VALIDATOR_REWARD_PREFIX, project_id, validator);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the rewards a validator can claim from an escrow
+pub fn get_validator_reward(env: &Env, project_id: u64, validator: &Address) -> Amount {
+    let key = (VALIDATOR_REWARD_PREFIX, project_id, validator);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store how many expired votes a validator has missed since their last slash
+pub fn set_missed_votes(env: &Env, project_id: u64, validator: &Address, missed: u32) {
+    let key = (MISSED_VOTES_PREFIX, project_id, validator);
+    env.storage().persistent().set(&key, &missed);
+}
+
+/// Retrieve how many expired votes a validator has missed since their last slash
+pub fn get_missed_votes(env: &Env, project_id: u64, validator: &Address) -> u32 {
+    let key = (MISSED_VOTES_PREFIX, project_id, validator);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the governance contract allowed to approve validator set changes
+pub fn set_governance_contract(env: &Env, governance_contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&GOVERNANCE_CONTRACT_KEY, governance_contract);
+}
+
+/// Retrieve the governance contract allowed to approve validator set changes
+pub fn get_governance_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&GOVERNANCE_CONTRACT_KEY)
+}
+
+/// Store the pending validator set change of an escrow
+pub fn set_validator_proposal(env: &Env, project_id: u64, proposal: &ValidatorProposal) {
+    let key = (VALIDATOR_PROPOSAL_PREFIX, project_id);
+    env.storage().persistent().set(&key, proposal);
+}
+
+/// Retrieve the pending validator set change of an escrow, if any
+pub fn get_validator_proposal(env: &Env, project_id: u64) -> Option<ValidatorProposal> {
+    let key = (VALIDATOR_PROPOSAL_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Remove the pending validator set change of an escrow
+pub fn clear_validator_proposal(env: &Env, project_id: u64) {
+    let key = (VALIDATOR_PROPOSAL_PREFIX, project_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Allocate the id of the next validator set change of an escrow
+pub fn next_validator_proposal_id(env: &Env, project_id: u64) -> u32 {
+    let key = (VALIDATOR_PROPOSAL_COUNTER_PREFIX, project_id);
+    let id: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(id + 1));
+    id
+}
+
+/// Store the validator weights snapshotted when a milestone was submitted
+pub fn set_vote_weights(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    weights: &Map<Address, Amount>,
+) {
+    let key = (VOTE_WEIGHTS_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, weights);
+}
+
+/// Retrieve the validator weights snapshotted for the latest submission
+pub fn get_vote_weights(env: &Env, project_id: u64, milestone_id: u64) -> Map<Address, Amount> {
+    let key = (VOTE_WEIGHTS_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Map::new(env))
+}
+
+/// Mark an escrow as abandoned
+pub fn set_abandoned(env: &Env, project_id: u64) {
+    let key = (ABANDONED_PREFIX, project_id);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check if an escrow has been abandoned
+pub fn is_abandoned(env: &Env, project_id: u64) -> bool {
+    let key = (ABANDONED_PREFIX, project_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Store the funds of an escrow as they stood when it was abandoned
+pub fn set_abandonment_snapshot(env: &Env, project_id: u64, snapshot: &AbandonmentSnapshot) {
+    let key = (ABANDON_SNAPSHOT_PREFIX, project_id);
+    env.storage().persistent().set(&key, snapshot);
+}
+
+/// Retrieve the funds of an escrow as they stood when it was abandoned, if it was
+pub fn get_abandonment_snapshot(env: &Env, project_id: u64) -> Option<AbandonmentSnapshot> {
+    let key = (ABANDON_SNAPSHOT_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store the time after which an unfinished escrow can be marked abandoned
+pub fn set_abandonment_deadline(env: &Env, project_id: u64, deadline: u64) {
+    let key = (ABANDON_DEADLINE_PREFIX, project_id);
+    env.storage().persistent().set(&key, &deadline);
+}
+
+/// Retrieve the abandonment deadline of an escrow, if any
+pub fn get_abandonment_deadline(env: &Env, project_id: u64) -> Option<u64> {
+    let key = (ABANDON_DEADLINE_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Record that a validator voted to abandon an escrow
+pub fn set_abandon_vote(env: &Env, project_id: u64, validator: &Address) {
+    let key = (ABANDON_VOTE_PREFIX, project_id, validator);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check if a validator has voted to abandon an escrow
+pub fn has_abandon_vote(env: &Env, project_id: u64, validator: &Address) -> bool {
+    let key = (ABANDON_VOTE_PREFIX, project_id, validator);
+    env.storage().persistent().has(&key)
+}
+
+/// Add to the total refunded to backers of an escrow
+pub fn add_refunded_amount(env: &Env, project_id: u64, amount: Amount) -> Result<(), Error> {
+    let key = (REFUNDED_PREFIX, project_id);
+    let updated = get_refunded_amount(env, project_id)
+        .checked_add(amount)
+        .ok_or(Error::InvalidInput)?;
+    env.storage().persistent().set(&key, &updated);
+    Ok(())
+}
+
+/// Retrieve the total refunded to backers of an escrow
+pub fn get_refunded_amount(env: &Env, project_id: u64) -> Amount {
+    let key = (REFUNDED_PREFIX, project_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the total refunded to a backer of an escrow
+pub fn set_refund_claimed(env: &Env, project_id: u64, backer: &Address, amount: Amount) {
+    let key = (REFUND_CLAIMED_PREFIX, project_id, backer);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the total refunded to a backer of an escrow
+pub fn get_refund_claimed(env: &Env, project_id: u64, backer: &Address) -> Amount {
+    let key = (REFUND_CLAIMED_PREFIX, project_id, backer);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Add to the failed-milestone refund owed per deposited unit of an escrow,
+/// scaled by `REFUND_RATE_SCALE`
+pub fn add_refund_rate(env: &Env, project_id: u64, rate: Amount) -> Result<(), Error> {
+    let key = (REFUND_RATE_PREFIX, project_id);
+    let updated = get_refund_rate(env, project_id)
+        .checked_add(rate)
+        .ok_or(Error::InvalidInput)?;
+    env.storage().persistent().set(&key, &updated);
+    Ok(())
+}
+
+/// Retrieve the failed-milestone refund owed per deposited unit of an escrow
+pub fn get_refund_rate(env: &Env, project_id: u64) -> Amount {
+    let key = (REFUND_RATE_PREFIX, project_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Add to the failed-milestone refunds accrued before a backer's deposits
+/// were made, which those deposits have no share in
+pub fn add_refund_debt(
+    env: &Env,
+    project_id: u64,
+    backer: &Address,
+    amount: Amount,
+) -> Result<(), Error> {
+    let key = (REFUND_DEBT_PREFIX, project_id, backer);
+    let updated = get_refund_debt(env, project_id, backer)
+        .checked_add(amount)
+        .ok_or(Error::InvalidInput)?;
+    env.storage().persistent().set(&key, &updated);
+    Ok(())
+}
+
+/// Retrieve the failed-milestone refunds accrued before a backer's deposits
+pub fn get_refund_debt(env: &Env, project_id: u64, backer: &Address) -> Amount {
+    let key = (REFUND_DEBT_PREFIX, project_id, backer);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Record how a validator voted on a milestone
 pub fn set_validator_vote(
     env: &Env,
     project_id: u64,
     milestone_id: u64,
     validator: &Address,
+    approve: bool,
 ) -> Result<(), Error> {
     let key = (VALIDATOR_VOTE_PREFIX, project_id, milestone_id, validator);
-    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().set(&key, &approve);
+
+    let voters_key = (MILESTONE_VOTERS_PREFIX, project_id, milestone_id);
+    let mut voters = get_milestone_voters(env, project_id, milestone_id);
+    voters.push_back(validator.clone());
+    env.storage().persistent().set(&voters_key, &voters);
     Ok(())
 }
 
+/// Retrieve the validators who voted on the latest submission of a milestone
+pub fn get_milestone_voters(env: &Env, project_id: u64, milestone_id: u64) -> Vec<Address> {
+    let key = (MILESTONE_VOTERS_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Retrieve how a validator voted on the latest submission of a milestone
+pub fn get_validator_vote(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    validator: &Address,
+) -> Option<bool> {
+    let key = (VALIDATOR_VOTE_PREFIX, project_id, milestone_id, validator);
+    env.storage().persistent().get(&key)
+}
+
 /// Check if a validator has already voted on a milestone
 pub fn has_validator_voted(
     env: &Env,
@@ -101,15 +644,16 @@ pub fn has_validator_voted(
     Ok(env.storage().persistent().has(&key))
 }
 
-/// Clear all validator votes for a milestone (used when resubmitting)
+/// Clear all validator votes for a milestone (used when resubmitting). Votes
+/// are cleared by recorded voter, so validators removed since still lose theirs
 pub fn clear_milestone_voters(env: &Env, project_id: u64, milestone_id: u64) {
-    // Get the escrow to know how many validators there are
-    if let Ok(escrow) = get_escrow(env, project_id) {
-        for validator in escrow.validators.iter() {
-            let key = (VALIDATOR_VOTE_PREFIX, project_id, milestone_id, validator);
-            env.storage().persistent().remove(&key);
-        }
+    for validator in get_milestone_voters(env, project_id, milestone_id).iter() {
+        let key = (VALIDATOR_VOTE_PREFIX, project_id, milestone_id, validator);
+        env.storage().persistent().remove(&key);
     }
+
+    let key = (MILESTONE_VOTERS_PREFIX, project_id, milestone_id);
+    env.storage().persistent().remove(&key);
 }
 
 /// Update vote counts for a milestone (alternative approach if needed)
@@ -130,7 +674,41 @@ pub fn set_milestone_votes(
     set_milestone(env, project_id, milestone_id, &milestone);
 }
 
-/// Calculate total amount allocated to approved and submitted milestones
+/// Check if any milestone of an escrow is awaiting validator votes
+pub fn has_submitted_milestone(env: &Env, project_id: u64) -> Result<bool, Error> {
+    let counter = get_milestone_counter(env, project_id)?;
+
+    for milestone_id in 0..counter {
+        if let Ok(milestone) = get_milestone(env, project_id, milestone_id) {
+            if milestone.status == MilestoneStatus::Submitted {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Calculate the amount of approved milestones still waiting to vest
+pub fn get_vesting_amount(env: &Env, project_id: u64) -> Result<Amount, Error> {
+    let counter = get_milestone_counter(env, project_id)?;
+    let mut total: Amount = 0;
+
+    for milestone_id in 0..counter {
+        if let Ok(milestone) = get_milestone(env, project_id, milestone_id) {
+            if milestone.status == MilestoneStatus::Approved {
+                total = total
+                    .checked_add(milestone.amount - milestone.released_amount)
+                    .ok_or(Error::InvalidInput)?;
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+/// Calculate total amount allocated to milestones; expired milestones give
+/// their amount back
 pub fn get_total_milestone_amount(env: &Env, project_id: u64) -> Result<Amount, Error> {
     // Get milestone counter to know how many milestones exist
     let counter = get_milestone_counter(env, project_id)?;
@@ -138,6 +716,9 @@ pub fn get_total_milestone_amount(env: &Env, project_id: u64) -> Result<Amount,
 
     for milestone_id in 0..counter {
         if let Ok(milestone) = get_milestone(env, project_id, milestone_id) {
+            if milestone.status == MilestoneStatus::Expired {
+                continue;
+            }
             total = total
                 .checked_add(milestone.amount)
                 .ok_or(Error::InvalidInput)?;
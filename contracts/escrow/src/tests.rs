@@ -2,9 +2,17 @@
 
 mod tests {
     use crate::{EscrowContract, EscrowContractClient};
-    use shared::types::MilestoneStatus;
+    use shared::{
+        errors::Error,
+        launch_escrow_id,
+        types::{
+            ChallengeConfig, MilestoneStatus, Tranche, ValidatorBondConfig, ValidatorChange,
+            VoteWeighting,
+        },
+    };
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
         Address, BytesN, Env, Vec,
     };
 
@@ -13,7 +21,7 @@ mod tests {
         env.ledger().set_timestamp(1000);
 
         let creator = Address::generate(&env);
-        let token = Address::generate(&env);
+        let token = create_funded_token(&env, &creator);
         let validator1 = Address::generate(&env);
         let validator2 = Address::generate(&env);
         let validator3 = Address::generate(&env);
@@ -26,6 +34,17 @@ mod tests {
         (env, creator, token, validator3, validators)
     }
 
+    /// Register a token and mint `holder` enough of it for the deposits below
+    fn create_funded_token(env: &Env, holder: &Address) -> Address {
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address();
+        StellarAssetClient::new(env, &token)
+            .mock_all_auths()
+            .mint(holder, &1_000_000);
+        token
+    }
+
     fn create_client(env: &Env) -> EscrowContractClient<'_> {
         EscrowContractClient::new(env, &env.register_contract(None, EscrowContract))
     }
@@ -37,7 +56,7 @@ mod tests {
     ) -> (Address, Address, Address, Vec<Address>, EscrowContractClient) {
         let admin = Address::generate(env);
         let creator = Address::generate(env);
-        let token = Address::generate(env);
+        let token = create_funded_token(env, &creator);
 
         let mut validators = Vec::new(env);
         validators.push_back(Address::generate(env));
@@ -53,8 +72,9 @@ mod tests {
         (admin, creator, token, validators, client)
     }
 
-    /// Default threshold used by all existing tests (67%).
-    const DEFAULT_THRESHOLD: u32 = 6700;
+    /// Default threshold used by all existing tests (66%, so two of three
+    /// validators decide once the requirement is rounded up).
+    const DEFAULT_THRESHOLD: u32 = 6600;
 
     // ── existing tests (approval_threshold argument added to every initialize call) ──
 
@@ -111,7 +131,7 @@ mod tests {
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
 
         let deposit_amount: i128 = 1000;
-        let result = client.try_deposit(&1, &deposit_amount);
+        let result = client.try_deposit(&1, &creator, &deposit_amount);
 
         assert!(result.is_ok());
 
@@ -127,11 +147,187 @@ mod tests {
 
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
 
-        let result = client.try_deposit(&1, &0);
+        let result = client.try_deposit(&1, &creator, &0);
         assert!(result.is_err());
 
-        let result = client.try_deposit(&1, &-100);
+        let result = client.try_deposit(&1, &creator, &-100);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deposit_pulls_tokens_from_depositor() {
+        let (env, creator, token, _, validators) = create_test_env();
+        let client = create_client(&env);
+        let token_client = TokenClient::new(&env, &token);
+
+        env.mock_all_auths();
+        client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &400);
+        client.deposit(&1, &backer, &400);
+        assert_eq!(env.auths()[0].0, backer);
+        client.deposit(&1, &creator, &600);
+
+        assert_eq!(token_client.balance(&backer), 0);
+        assert_eq!(token_client.balance(&client.address), 1000);
+        assert_eq!(client.get_deposit_from_source(&1, &backer), 400);
+        assert_eq!(client.get_escrow(&1).total_deposited, 1000);
+
+        // Deposits beyond the depositor's balance move nothing
+        let result = client.try_deposit(&1, &backer, &1);
+        assert!(result.is_err());
+        assert_eq!(client.get_escrow(&1).total_deposited, 1000);
+    }
+
+    #[test]
+    fn test_recorded_balance_reconciles_with_token_balance() {
+        let (env, creator, token, _, validators) = create_test_env();
+        let client = create_client(&env);
+        let token_client = TokenClient::new(&env, &token);
+
+        env.mock_all_auths();
+        client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+        client.initialize(&2, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+        client.deposit(&1, &creator, &1000);
+        client.deposit(&2, &creator, &500);
+        assert_eq!(client.get_recorded_balance(&token), 1500);
+        assert_eq!(client.get_unrecorded_balance(&token), 0);
+
+        // Tokens sent straight to the contract are not credited to any escrow
+        token_client.transfer(&creator, &client.address, &250);
+        assert_eq!(client.get_recorded_balance(&token), 1500);
+        assert_eq!(client.get_unrecorded_balance(&token), 250);
+
+        // Releases leave the books and the balance in step
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &400, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+        assert_eq!(client.get_recorded_balance(&token), 1100);
+        assert_eq!(client.get_unrecorded_balance(&token), 250);
+    }
+
+    #[test]
+    fn test_deposit_from_launch_pulls_tokens_from_launch_contract() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+
+        let launch = Address::generate(&env);
+        client.set_launch_contract(&launch);
+
+        // The launch contract holds nothing, so nothing can be credited
+        let result = client.try_deposit_from_launch(
+            &1,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &1000,
+        );
         assert!(result.is_err());
+
+        StellarAssetClient::new(&env, &token).mint(&launch, &1000);
+        client.deposit_from_launch(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &1000);
+        let escrow_id = launch_escrow_id(1);
+        assert_eq!(TokenClient::new(&env, &token).balance(&launch), 0);
+        assert_eq!(client.get_escrow(&escrow_id).total_deposited, 1000);
+        assert_eq!(client.get_deposit_from_source(&escrow_id, &launch), 1000);
+        assert_eq!(client.get_unrecorded_balance(&token), 0);
+    }
+
+    #[test]
+    fn test_deposit_from_launch_initializes_and_tops_up() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, _, _, validators, client) = setup_with_admin(&env);
+
+        let launch = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &launch);
+        client.set_launch_contract(&launch);
+        assert_eq!(client.get_launch_contract(), Some(launch.clone()));
+
+        // First deposit initializes the escrow for the project
+        client.deposit_from_launch(&7, &creator, &token, &validators, &DEFAULT_THRESHOLD, &1000);
+        let escrow = client.get_escrow(&launch_escrow_id(7));
+        assert_eq!(escrow.creator, creator);
+        assert_eq!(escrow.total_deposited, 1000);
+        assert_eq!(escrow.approval_threshold, DEFAULT_THRESHOLD);
+
+        // Second deposit tops up the existing escrow
+        client.deposit_from_launch(&7, &creator, &token, &validators, &DEFAULT_THRESHOLD, &500);
+        assert_eq!(
+            client.get_escrow(&launch_escrow_id(7)).total_deposited,
+            1500
+        );
+        assert_eq!(
+            client.get_deposit_from_source(&launch_escrow_id(7), &launch),
+            1500
+        );
+    }
+
+    #[test]
+    fn test_deposit_from_launch_requires_configured_launch_contract() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+
+        let result = client.try_deposit_from_launch(
+            &1,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &1000,
+        );
+        assert!(
+            result.is_err(),
+            "deposit_from_launch should fail without a launch contract"
+        );
+    }
+
+    #[test]
+    fn test_launch_escrows_cannot_be_initialized_directly() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+
+        let result = client.try_initialize(
+            &launch_escrow_id(2),
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        // Escrow 1 was initialized directly; the launch escrow of project 1 is separate
+        let launch = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&launch, &1000);
+        client.set_launch_contract(&launch);
+        client.deposit_from_launch(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &600);
+        assert_eq!(client.get_escrow(&1).total_deposited, 0);
+        assert_eq!(client.get_escrow(&launch_escrow_id(1)).total_deposited, 600);
+
+        // Top-ups must match the creator and token the escrow was created with
+        let other_creator = Address::generate(&env);
+        let result = client.try_deposit_from_launch(
+            &1,
+            &other_creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &400,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        assert_eq!(client.get_escrow(&launch_escrow_id(1)).total_deposited, 600);
     }
 
     #[test]
@@ -141,10 +337,10 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let milestone = client.get_milestone(&1, &0);
         assert_eq!(milestone.id, 0);
@@ -161,10 +357,10 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &500);
+        client.deposit(&1, &creator, &500);
 
         let description_hash = BytesN::from_array(&env, &[2u8; 32]);
-        let result = client.try_create_milestone(&1, &description_hash, &1000);
+        let result = client.try_create_milestone(&1, &description_hash, &1000, &None, &None, &None);
 
         assert!(result.is_err());
     }
@@ -176,15 +372,15 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &3000);
+        client.deposit(&1, &creator, &3000);
 
         let desc1 = BytesN::from_array(&env, &[1u8; 32]);
         let desc2 = BytesN::from_array(&env, &[2u8; 32]);
         let desc3 = BytesN::from_array(&env, &[3u8; 32]);
 
-        client.create_milestone(&1, &desc1, &1000);
-        client.create_milestone(&1, &desc2, &1000);
-        client.create_milestone(&1, &desc3, &1000);
+        client.create_milestone(&1, &desc1, &1000, &None, &None, &None);
+        client.create_milestone(&1, &desc2, &1000, &None, &None, &None);
+        client.create_milestone(&1, &desc3, &1000, &None, &None, &None);
 
         assert!(client.get_milestone(&1, &0).id == 0);
         assert!(client.get_milestone(&1, &1).id == 1);
@@ -201,10 +397,10 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
         client.submit_milestone(&1, &0, &proof_hash);
@@ -221,10 +417,10 @@ mod tests {
         env.mock_all_auths();
 
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
         client.submit_milestone(&1, &0, &proof_hash);
@@ -243,11 +439,11 @@ mod tests {
 
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
 
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
         let balance = client.get_available_balance(&1);
         assert_eq!(balance, 1000);
 
-        client.deposit(&1, &500);
+        client.deposit(&1, &creator, &500);
         let balance = client.get_available_balance(&1);
         assert_eq!(balance, 1500);
     }
@@ -280,10 +476,10 @@ mod tests {
         env.mock_all_auths();
 
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let milestone = client.get_milestone(&1, &0);
         assert_eq!(milestone.status, MilestoneStatus::Pending);
@@ -306,13 +502,13 @@ mod tests {
 
         client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
 
-        client.deposit(&1, &500);
+        client.deposit(&1, &creator, &500);
         assert_eq!(client.get_escrow(&1).total_deposited, 500);
 
-        client.deposit(&1, &300);
+        client.deposit(&1, &creator, &300);
         assert_eq!(client.get_escrow(&1).total_deposited, 800);
 
-        client.deposit(&1, &200);
+        client.deposit(&1, &creator, &200);
         assert_eq!(client.get_escrow(&1).total_deposited, 1000);
     }
 
@@ -432,13 +628,13 @@ mod tests {
         let contract_id = env.register_contract(None, EscrowContract);
         let client = EscrowContractClient::new(&env, &contract_id);
 
-        soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+        StellarAssetClient::new(&env, &token).mint(&creator, &1000);
 
         client.initialize(&1, &creator, &token, &validators, &10000);
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
         client.submit_milestone(&1, &0, &proof_hash);
@@ -465,8 +661,8 @@ mod tests {
         );
     }
 
-    /// 67% threshold — 2 out of 3 validators are required.
-    /// (3 * 6700) / 10000 = 2 (integer division).
+    /// 66% threshold — 2 out of 3 validators are required.
+    /// (3 * 6600) / 10000 = 1.98, rounded up to 2.
     /// Vote 1 must leave milestone as Submitted; vote 2 triggers Approved.
     #[test]
     fn test_majority_threshold_enforced() {
@@ -492,13 +688,13 @@ mod tests {
         let contract_id = env.register_contract(None, EscrowContract);
         let client = EscrowContractClient::new(&env, &contract_id);
 
-        soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+        StellarAssetClient::new(&env, &token).mint(&creator, &1000);
 
-        client.initialize(&1, &creator, &token, &validators, &6700);
-        client.deposit(&1, &1000);
+        client.initialize(&1, &creator, &token, &validators, &6600);
+        client.deposit(&1, &creator, &1000);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
 
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
         client.submit_milestone(&1, &0, &proof_hash);
@@ -507,17 +703,1027 @@ mod tests {
         assert_eq!(
             client.get_milestone(&1, &0).status,
             MilestoneStatus::Submitted,
-            "one approval should not meet the 67% threshold with 3 validators"
+            "one approval should not meet the 66% threshold with 3 validators"
         );
 
         client.vote_milestone(&1, &0, &v2, &true);
         assert_eq!(
             client.get_milestone(&1, &0).status,
             MilestoneStatus::Approved,
-            "two approvals should meet the 67% threshold with 3 validators"
+            "two approvals should meet the 66% threshold with 3 validators"
+        );
+    }
+
+    #[test]
+    fn test_rejected_milestone_can_be_resubmitted() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+
+        let first_proof = BytesN::from_array(&env, &[9u8; 32]);
+        client.submit_milestone(&1, &0, &first_proof);
+        client.vote_milestone(&1, &0, &v1, &false);
+        client.vote_milestone(&1, &0, &v2, &false);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+
+        // Revised proof opens a fresh vote
+        let second_proof = BytesN::from_array(&env, &[10u8; 32]);
+        env.ledger().set_timestamp(2000);
+        client.submit_milestone(&1, &0, &second_proof);
+        let milestone = client.get_milestone(&1, &0);
+        assert_eq!(milestone.status, MilestoneStatus::Submitted);
+        assert_eq!(milestone.resubmission_count, 1);
+        assert_eq!(milestone.rejection_count, 0);
+
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(
+            TokenClient::new(&env, &token).balance(&creator),
+            1_000_000 - 500
+        );
+
+        let history = client.get_milestone_history(&1, &0);
+        assert_eq!(history.len(), 2);
+        let first = history.get(0).unwrap();
+        assert_eq!(first.proof_hash, first_proof);
+        assert_eq!(first.status, MilestoneStatus::Rejected);
+        assert_eq!(first.rejection_count, 2);
+        assert_eq!(first.decided_at, 1000);
+        let second = history.get(1).unwrap();
+        assert_eq!(second.proof_hash, second_proof);
+        assert_eq!(second.status, MilestoneStatus::Approved);
+        assert_eq!(second.approval_count, 2);
+        assert_eq!(second.decided_at, 2000);
+    }
+
+    #[test]
+    fn test_milestone_fails_after_resubmission_limit() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        assert_eq!(
+            client.get_max_resubmissions(),
+            shared::DEFAULT_MAX_RESUBMISSIONS
         );
+        client.set_max_resubmissions(&1);
+        assert_eq!(client.get_max_resubmissions(), 1);
+
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+
+        for round in 0..2u8 {
+            let proof_hash = BytesN::from_array(&env, &[round; 32]);
+            client.submit_milestone(&1, &0, &proof_hash);
+            client.vote_milestone(&1, &0, &v1, &false);
+            client.vote_milestone(&1, &0, &v2, &false);
+        }
+
+        let milestone = client.get_milestone(&1, &0);
+        assert_eq!(milestone.status, MilestoneStatus::Failed);
+        assert_eq!(milestone.resubmission_count, 1);
+        assert_eq!(client.get_refundable_amount(&1), 500);
+        assert_eq!(client.get_milestone_history(&1, &0).len(), 2);
+
+        let proof_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let result = client.try_submit_milestone(&1, &0, &proof_hash);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+    }
+
+    #[test]
+    fn test_validators_abandon_escrow_and_backers_claim() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let token_client = TokenClient::new(&env, &token);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &400);
+        client.deposit(&1, &creator, &600);
+        client.deposit(&1, &backer, &400);
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+
+        // Nothing is refundable while the escrow is live
+        let result = client.try_claim_refund(&1, &backer);
+        assert_eq!(result, Err(Ok(Error::NoClaimableAmount)));
+
+        let result = client.try_vote_abandon(&1, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::NotAValidator)));
+        client.vote_abandon(&1, &v1);
+        let result = client.try_vote_abandon(&1, &v1);
+        assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+        assert!(!client.is_abandoned(&1));
+
+        // Two of three meet 66%
+        client.vote_abandon(&1, &v2);
+        assert!(client.is_abandoned(&1));
+
+        // Abandoned escrows take no further deposits or milestones
+        let result = client.try_deposit(&1, &creator, &100);
+        assert_eq!(result, Err(Ok(Error::ProjectNotActive)));
+        let result = client.try_create_milestone(&1, &description_hash, &100, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::ProjectNotActive)));
+
+        // The unreleased 500 is split 60/40 by deposit
+        assert_eq!(client.get_claimable_refund(&1, &creator), 300);
+        assert_eq!(client.claim_refund(&1, &backer), 200);
+        assert_eq!(token_client.balance(&backer), 200);
+        client.claim_refund(&1, &creator);
+
+        let result = client.try_claim_refund(&1, &backer);
+        assert_eq!(result, Err(Ok(Error::NoClaimableAmount)));
+        assert_eq!(client.get_refunded_amount(&1), 500);
+        assert_eq!(client.get_available_balance(&1), 0);
+        assert_eq!(token_client.balance(&client.address), 0);
+        assert_eq!(client.get_recorded_balance(&token), 0);
     }
 
+    #[test]
+    fn test_vote_abandon_rounds_required_votes_up() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        client.initialize(&2, &creator, &token, &validators, &6000);
+
+        // 60% of three validators is 1.8, so one vote is not enough
+        client.vote_abandon(&2, &validators.get(0).unwrap());
+        assert!(!client.is_abandoned(&2));
+        client.vote_abandon(&2, &validators.get(1).unwrap());
+        assert!(client.is_abandoned(&2));
+    }
+
+    #[test]
+    fn test_vote_milestone_rounds_required_weight_up() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        client.initialize(&2, &creator, &token, &validators, &6000);
+        client.deposit(&2, &creator, &1000);
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&2, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&2, &0, &BytesN::from_array(&env, &[9u8; 32]));
+
+        // 60% of three equal weights is 1.8, so one approval is not enough
+        client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+        let milestone = client.get_milestone(&2, &0);
+        assert_eq!(milestone.status, MilestoneStatus::Submitted);
+        assert_eq!(client.get_available_balance(&2), 500);
+    }
+
+    #[test]
+    fn test_failed_milestone_refunds_and_abandonment_deadline() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        client.set_max_resubmissions(&0);
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &400, &None, &None, &None);
+        client.create_milestone(&1, &description_hash, &600, &None, &None, &None);
+
+        // A failed milestone's amount is refundable before any abandonment
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&1, &0, &v1, &false);
+        client.vote_milestone(&1, &0, &v2, &false);
+        assert_eq!(client.claim_refund(&1, &creator), 400);
+        assert_eq!(client.get_available_balance(&1), 0);
+
+        let result = client.try_mark_abandoned(&1);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        // A deadline that already passed would skip the grace period
+        let result = client.try_set_abandonment_deadline(&1, &1000);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        client.set_abandonment_deadline(&1, &5000);
+        assert_eq!(client.get_abandonment_deadline(&1), Some(5000));
+
+        env.ledger().set_timestamp(5000);
+        let result = client.try_mark_abandoned(&1);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        env.ledger().set_timestamp(5001);
+        client.mark_abandoned(&1);
+        assert!(client.is_abandoned(&1));
+        let result = client.try_submit_milestone(&1, &1, &BytesN::from_array(&env, &[9u8; 32]));
+        assert_eq!(result, Err(Ok(Error::ProjectNotActive)));
+
+        // Only the part not yet refunded is left to claim
+        assert_eq!(client.claim_refund(&1, &creator), 600);
+        assert_eq!(client.get_available_balance(&1), 0);
+    }
+
+    #[test]
+    fn test_failed_milestone_refunds_exclude_later_deposits() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &100);
+        client.set_max_resubmissions(&0);
+        client.deposit(&1, &creator, &100);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &50, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&1, &0, &v1, &false);
+        client.vote_milestone(&1, &0, &v2, &false);
+        assert_eq!(client.claim_refund(&1, &creator), 50);
+
+        // A deposit made after the failure has no share in it
+        client.deposit(&1, &backer, &100);
+        assert_eq!(client.get_claimable_refund(&1, &backer), 0);
+        let result = client.try_claim_refund(&1, &backer);
+        assert_eq!(result, Err(Ok(Error::NoClaimableAmount)));
+
+        // The refunded 50 is not split again on abandonment
+        client.vote_abandon(&1, &v1);
+        client.vote_abandon(&1, &v2);
+        assert_eq!(client.claim_refund(&1, &creator), 75);
+        assert_eq!(client.claim_refund(&1, &backer), 75);
+        assert_eq!(client.get_refunded_amount(&1), 200);
+        assert_eq!(TokenClient::new(&env, &token).balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_overdue_pending_milestone_expires() {
+        let (env, creator, token, _, validators) = create_test_env();
+        let client = create_client(&env);
+        env.mock_all_auths();
+
+        client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+        client.deposit(&1, &creator, &1000);
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let result =
+            client.try_create_milestone(&1, &description_hash, &600, &Some(1000), &None, &None);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        client.create_milestone(&1, &description_hash, &600, &Some(2000), &None, &None);
+        assert_eq!(client.get_available_balance(&1), 400);
+
+        let result = client.try_expire_milestone(&1, &0);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+
+        env.ledger().set_timestamp(2001);
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        let result = client.try_submit_milestone(&1, &0, &proof_hash);
+        assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+
+        client.expire_milestone(&1, &0);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Expired
+        );
+
+        // The expired amount can be allocated again
+        assert_eq!(client.get_total_milestone_amount(&1), 0);
+        assert_eq!(client.get_available_balance(&1), 1000);
+        client.create_milestone(&1, &description_hash, &1000, &None, &None, &None);
+    }
+
+    #[test]
+    fn test_undecided_submission_resolves_to_expiry_outcome() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let v1 = validators.get(0).unwrap();
+
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &Some(100), &None);
+
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &v1, &true);
+
+        let result = client.try_expire_milestone(&1, &0);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+
+        // Late votes are refused and the default outcome is a rejection
+        env.ledger().set_timestamp(1101);
+        let result = client.try_vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+        assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+        assert_eq!(client.get_expiry_outcome(), MilestoneStatus::Rejected);
+        client.expire_milestone(&1, &0);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+
+        let result = client.try_set_expiry_outcome(&MilestoneStatus::Pending);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        client.set_expiry_outcome(&MilestoneStatus::Approved);
+
+        // The window restarts with each submission
+        client.submit_milestone(&1, &0, &proof_hash);
+        env.ledger().set_timestamp(1201);
+        client.vote_milestone(&1, &0, &v1, &false);
+        env.ledger().set_timestamp(1202);
+        client.expire_milestone(&1, &0);
+
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(
+            TokenClient::new(&env, &token).balance(&creator),
+            1_000_000 - 500
+        );
+        assert_eq!(client.get_available_balance(&1), 500);
+    }
+
+    #[test]
+    fn test_milestone_tranches_vest_after_approval() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+
+        client.deposit(&1, &creator, &1000);
+        let mut tranches = Vec::new(&env);
+        tranches.push_back(Tranche {
+            share: 3000,
+            delay: 0,
+        });
+        tranches.push_back(Tranche {
+            share: 7000,
+            delay: 500,
+        });
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &800, &None, &None, &Some(tranches));
+
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+
+        // Only the first tranche is paid on approval; the rest stays reserved
+        let token_client = TokenClient::new(&env, &token);
+        assert_eq!(token_client.balance(&creator), 1_000_000 - 1000 + 240);
+        assert_eq!(client.get_available_balance(&1), 200);
+
+        let schedule = client.get_tranche_schedule(&1, &0);
+        assert_eq!(schedule.get(1).unwrap().amount, 560);
+        assert_eq!(schedule.get(1).unwrap().vests_at, 1500);
+        assert!(schedule.get(0).unwrap().released);
+        assert!(!schedule.get(1).unwrap().released);
+
+        let result = client.try_release_tranches(&1, &0);
+        assert_eq!(result, Err(Ok(Error::NoClaimableAmount)));
+
+        env.ledger().set_timestamp(1500);
+        assert_eq!(client.release_tranches(&1, &0), 560);
+        assert_eq!(token_client.balance(&creator), 1_000_000 - 1000 + 800);
+        assert_eq!(client.get_milestone(&1, &0).released_amount, 800);
+        assert_eq!(client.get_available_balance(&1), 200);
+    }
+
+    #[test]
+    fn test_invalid_tranche_schedules_are_rejected() {
+        let (env, creator, token, _, validators) = create_test_env();
+        let client = create_client(&env);
+        env.mock_all_auths();
+
+        client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+        let schedules = [
+            Vec::new(&env),
+            Vec::from_array(
+                &env,
+                [Tranche {
+                    share: 6000,
+                    delay: 0,
+                }],
+            ),
+            Vec::from_array(
+                &env,
+                [Tranche {
+                    share: 6000,
+                    delay: 0,
+                }],
+            ),
+            Vec::from_array(
+                &env,
+                [
+                    Tranche { share: 0, delay: 0 },
+                    Tranche {
+                        share: 10000,
+                        delay: 10,
+                    },
+                ],
+            ),
+            Vec::from_array(
+                &env,
+                [
+                    Tranche {
+                        share: 5000,
+                        delay: 10,
+                    },
+                    Tranche {
+                        share: 5000,
+                        delay: 0,
+                    },
+                ],
+            ),
+        ];
+        for tranches in schedules {
+            let tranches = Some(tranches);
+            let result =
+                client.try_create_milestone(&1, &description_hash, &500, &None, &None, &tranches);
+            assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        }
+    }
+
+    #[test]
+    fn test_approved_milestone_waits_for_challenge_window() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let token_client = TokenClient::new(&env, &token);
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &400);
+        client.deposit(&1, &creator, &600);
+        client.deposit(&1, &backer, &400);
+        client.set_challenge_config(&ChallengeConfig {
+            period: 100,
+            dispute_threshold: 5000,
+            arbiters: Vec::from_array(&env, [Address::generate(&env)]),
+        });
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+
+        let milestone = client.get_milestone(&1, &0);
+        assert_eq!(milestone.status, MilestoneStatus::PendingRelease);
+        assert_eq!(milestone.challenge_ends_at, 1100);
+        assert_eq!(token_client.balance(&creator), 1_000_000 - 600);
+        let result = client.try_finalize_release(&1, &0);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        // A backer below the dispute threshold cannot freeze the release alone
+        client.dispute_milestone(&1, &0, &backer);
+        assert_eq!(client.get_dispute(&1, &0).unwrap().stake, 400);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::PendingRelease
+        );
+        let result = client.try_dispute_milestone(&1, &0, &backer);
+        assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+        let result = client.try_dispute_milestone(&1, &0, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        env.ledger().set_timestamp(1100);
+        let result = client.try_dispute_milestone(&1, &0, &creator);
+        assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+        client.finalize_release(&1, &0);
+
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(token_client.balance(&creator), 1_000_000 - 600 + 500);
+        assert_eq!(client.get_dispute(&1, &0), None);
+    }
+
+    #[test]
+    fn test_dispute_from_launch_is_capped_by_launch_deposit() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, _, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        let launch = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let backer = Address::generate(&env);
+        let token = create_funded_token(&env, &launch);
+        client.set_launch_contract(&launch);
+        client.set_challenge_config(&ChallengeConfig {
+            period: 100,
+            dispute_threshold: 3000,
+            arbiters: Vec::from_array(&env, [Address::generate(&env)]),
+        });
+
+        client.deposit_from_launch(&7, &creator, &token, &validators, &DEFAULT_THRESHOLD, &1000);
+        let escrow_id = launch_escrow_id(7);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&escrow_id, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&escrow_id, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        client.vote_milestone(&escrow_id, &0, &v1, &true);
+        client.vote_milestone(&escrow_id, &0, &v2, &true);
+
+        // Launch contributors hold no direct deposit of their own
+        let result = client.try_dispute_milestone(&escrow_id, &0, &backer);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        let result = client.try_dispute_from_launch(&7, &0, &backer, &1001);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        assert_eq!(client.dispute_from_launch(&7, &0, &backer, &300), 1100);
+        assert_eq!(
+            client.get_milestone(&escrow_id, &0).status,
+            MilestoneStatus::Disputed
+        );
+        let result = client.try_dispute_from_launch(&7, &0, &backer, &300);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+    }
+
+    #[test]
+    fn test_disputed_milestone_is_decided_by_arbiters() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &400);
+        client.deposit(&1, &creator, &600);
+        client.deposit(&1, &backer, &400);
+
+        let arbiters = Vec::from_array(
+            &env,
+            [
+                Address::generate(&env),
+                Address::generate(&env),
+                Address::generate(&env),
+            ],
+        );
+        let mut config = ChallengeConfig {
+            period: 100,
+            dispute_threshold: 3000,
+            arbiters: Vec::new(&env),
+        };
+        let result = client.try_set_challenge_config(&config);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        let arbiter = arbiters.get(0).unwrap();
+        config.arbiters = Vec::from_array(&env, [arbiter.clone(), arbiter]);
+        let result = client.try_set_challenge_config(&config);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        config.arbiters = arbiters.clone();
+        client.set_challenge_config(&config);
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+
+        client.dispute_milestone(&1, &0, &backer);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Disputed
+        );
+
+        // Disputed funds stay frozen after the window closes
+        env.ledger().set_timestamp(1200);
+        let result = client.try_finalize_release(&1, &0);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+        let result = client.try_resolve_dispute(&1, &0, &v1, &true);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        client.resolve_dispute(&1, &0, &arbiters.get(0).unwrap(), &false);
+        client.resolve_dispute(&1, &0, &arbiters.get(1).unwrap(), &false);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+        assert_eq!(client.get_available_balance(&1), 500);
+
+        // A resubmission starts a new round the backer can dispute again
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        client.dispute_milestone(&1, &0, &backer);
+
+        // Arbiters configured after the dispute opened have no say in it
+        let newcomer = Address::generate(&env);
+        config.arbiters = Vec::from_array(&env, [newcomer.clone()]);
+        client.set_challenge_config(&config);
+        let result = client.try_resolve_dispute(&1, &0, &newcomer, &true);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        client.resolve_dispute(&1, &0, &arbiters.get(0).unwrap(), &true);
+        let result = client.try_resolve_dispute(&1, &0, &arbiters.get(0).unwrap(), &true);
+        assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+        client.resolve_dispute(&1, &0, &arbiters.get(2).unwrap(), &true);
+
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(
+            TokenClient::new(&env, &token).balance(&creator),
+            1_000_000 - 600 + 500
+        );
+    }
+
+    #[test]
+    fn test_stake_weighted_votes_use_submission_snapshot() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (admin, creator, token, validators, client) = setup_with_admin(&env);
+        let token_client = TokenClient::new(&env, &token);
+        let (v1, v2) = (validators.get(0).unwrap(), validators.get(1).unwrap());
+
+        client.deposit(&1, &creator, &1000);
+        for (validator, stake) in validators.iter().zip([700, 200, 100]) {
+            StellarAssetClient::new(&env, &token).mint(&validator, &stake);
+            client.lock_stake(&1, &validator, &stake);
+        }
+        let result = client.try_lock_stake(&1, &Address::generate(&env), &100);
+        assert_eq!(result, Err(Ok(Error::NotAValidator)));
+        assert_eq!(client.get_recorded_balance(&token), 2000);
+        client.set_vote_weighting(&1, &VoteWeighting::Stake);
+        assert_eq!(env.auths()[0].0, admin);
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+
+        // The weighting is fixed while a submission awaits votes
+        let result = client.try_set_vote_weighting(&1, &VoteWeighting::Equal);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+
+        // Withdrawing stake after the submission does not change the vote
+        client.unlock_stake(&1, &v1, &700);
+        assert_eq!(client.withdraw_stake(&1, &v1), 700);
+        assert_eq!(token_client.balance(&v1), 700);
+        let result = client.try_unlock_stake(&1, &v2, &300);
+        assert_eq!(result, Err(Ok(Error::InsufficientFunds)));
+        assert_eq!(client.get_vote_weights(&1, &0).get(v1.clone()), Some(700));
+        assert_eq!(client.get_milestone(&1, &0).total_weight, 1000);
+
+        client.vote_milestone(&1, &0, &v1, &true);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(client.get_recorded_balance(&token), 800);
+    }
+
+    #[test]
+    fn test_reputation_weighted_votes() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2, v3) = (
+            validators.get(0).unwrap(),
+            validators.get(1).unwrap(),
+            validators.get(2).unwrap(),
+        );
+
+        let result = client.try_set_vote_weighting(&1, &VoteWeighting::Reputation);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        let reputation_id = env.register_contract(None, reputation::ReputationContract);
+        let reputation_client = reputation::ReputationContractClient::new(&env, &reputation_id);
+        reputation_client.initialize(&Address::generate(&env));
+        for validator in validators.iter() {
+            reputation_client.register_user(&validator);
+        }
+        reputation_client.update_score(&v3, &900);
+        client.set_reputation_contract(&reputation_id);
+        client.set_vote_weighting(&1, &VoteWeighting::Reputation);
+
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        assert_eq!(client.get_milestone(&1, &0).total_weight, 1200);
+
+        // Two low-reputation approvals fall short; the heavy rejection decides
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Submitted
+        );
+        client.vote_milestone(&1, &0, &v3, &false);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_bonded_validators_earn_rewards_and_unbond() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        let token_client = TokenClient::new(&env, &token);
+        let (v1, v2, v3) = (
+            validators.get(0).unwrap(),
+            validators.get(1).unwrap(),
+            validators.get(2).unwrap(),
+        );
+
+        let mut config = ValidatorBondConfig {
+            min_bond: 100,
+            reward_share: 10001,
+            slash_share: 5000,
+            max_missed_votes: 0,
+            unbonding_period: 50,
+        };
+        let result = client.try_set_bond_config(&config);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        config.reward_share = 1000;
+        client.set_bond_config(&config);
+
+        client.deposit(&1, &creator, &1000);
+        for validator in [v1.clone(), v2.clone()] {
+            StellarAssetClient::new(&env, &token).mint(&validator, &100);
+            client.lock_stake(&1, &validator, &100);
+        }
+
+        // Only bonded validators review the submission
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        assert_eq!(client.get_milestone(&1, &0).total_weight, 2);
+        let result = client.try_vote_milestone(&1, &0, &v3, &true);
+        assert_eq!(result, Err(Ok(Error::NotAValidator)));
+
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(token_client.balance(&creator), 1_000_000 - 1000 + 450);
+        assert_eq!(client.get_validator_reward(&1, &v1), 25);
+        assert_eq!(client.claim_validator_rewards(&1, &v1), 25);
+        let result = client.try_claim_validator_rewards(&1, &v1);
+        assert_eq!(result, Err(Ok(Error::NoClaimableAmount)));
+
+        client.unlock_stake(&1, &v2, &100);
+        assert_eq!(client.get_unbonding(&1, &v2).available_at, 1050);
+        let result = client.try_withdraw_stake(&1, &v2);
+        assert_eq!(result, Err(Ok(Error::WithdrawalLocked)));
+        env.ledger().set_timestamp(1050);
+        assert_eq!(client.withdraw_stake(&1, &v2), 100);
+        assert_eq!(client.get_unrecorded_balance(&token), 0);
+    }
+
+    #[test]
+    fn test_validators_slashed_for_disputes_and_missed_votes() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (admin, creator, token, validators, client) = setup_with_admin(&env);
+        let token_client = TokenClient::new(&env, &token);
+        let (v1, v2, v3) = (
+            validators.get(0).unwrap(),
+            validators.get(1).unwrap(),
+            validators.get(2).unwrap(),
+        );
+
+        client.set_bond_config(&ValidatorBondConfig {
+            min_bond: 0,
+            reward_share: 0,
+            slash_share: 5000,
+            max_missed_votes: 2,
+            unbonding_period: 0,
+        });
+        let arbiter = Address::generate(&env);
+        client.set_challenge_config(&ChallengeConfig {
+            period: 100,
+            dispute_threshold: 3000,
+            arbiters: Vec::from_array(&env, [arbiter.clone()]),
+        });
+
+        let backer = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&backer, &400);
+        client.deposit(&1, &creator, &600);
+        client.deposit(&1, &backer, &400);
+        for validator in validators.iter() {
+            StellarAssetClient::new(&env, &token).mint(&validator, &100);
+            client.lock_stake(&1, &validator, &100);
+        }
+
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &Some(10), &None);
+
+        // A round that expires counts a miss against everyone who stayed silent
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &v1, &true);
+        env.ledger().set_timestamp(1011);
+        client.expire_milestone(&1, &0);
+        assert_eq!(client.get_missed_votes(&1, &v1), 0);
+        assert_eq!(client.get_missed_votes(&1, &v3), 1);
+
+        // Approvers overturned by the arbiters are slashed
+        client.submit_milestone(&1, &0, &proof_hash);
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        client.dispute_milestone(&1, &0, &backer);
+        client.resolve_dispute(&1, &0, &arbiter, &false);
+        assert_eq!(client.get_validator_stake(&1, &v1), 50);
+        assert_eq!(client.get_validator_stake(&1, &v2), 50);
+        assert_eq!(client.get_validator_stake(&1, &v3), 100);
+
+        // Reaching the missed vote limit slashes the bond and resets the count
+        env.ledger().set_timestamp(1200);
+        client.submit_milestone(&1, &0, &proof_hash);
+        env.ledger().set_timestamp(1211);
+        client.expire_milestone(&1, &0);
+        assert_eq!(client.get_validator_stake(&1, &v3), 50);
+        assert_eq!(client.get_validator_stake(&1, &v2), 25);
+        assert_eq!(client.get_missed_votes(&1, &v3), 0);
+        assert_eq!(client.get_missed_votes(&1, &v1), 1);
+
+        assert_eq!(token_client.balance(&admin), 175);
+        assert_eq!(client.get_unrecorded_balance(&token), 0);
+    }
+
+    #[test]
+    fn test_creator_proposes_validator_changes() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2, v3) = (
+            validators.get(0).unwrap(),
+            validators.get(1).unwrap(),
+            validators.get(2).unwrap(),
+        );
+        let newcomer = Address::generate(&env);
+
+        // Duplicates and sets below the minimum are refused
+        let result = client.try_propose_validator_change(&1, &ValidatorChange::Add(v1.clone()));
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        let result = client.try_propose_validator_change(&1, &ValidatorChange::Remove(v1.clone()));
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        let mut duplicated = validators.clone();
+        duplicated.push_back(v1.clone());
+        let result = client.try_update_validators(&1, &duplicated);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        client.propose_validator_change(&1, &ValidatorChange::Add(newcomer.clone()));
+        let change = ValidatorChange::Add(creator.clone());
+        let result = client.try_propose_validator_change(&1, &change);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+        let result = client.try_approve_validator_change(&1, &newcomer);
+        assert_eq!(result, Err(Ok(Error::NotAValidator)));
+
+        client.approve_validator_change(&1, &v1);
+        let result = client.try_approve_validator_change(&1, &v1);
+        assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+        assert_eq!(client.get_escrow(&1).validators.len(), 3);
+        client.approve_validator_change(&1, &v2);
+        assert_eq!(client.get_escrow(&1).validators.len(), 4);
+        assert_eq!(client.get_validator_proposal(&1), None);
+
+        // Governance can approve too, but not while a milestone awaits votes
+        let governance = Address::generate(&env);
+        client.set_governance_contract(&governance);
+        client.propose_validator_change(&1, &ValidatorChange::Remove(newcomer.clone()));
+
+        client.deposit(&1, &creator, &1000);
+        let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
+        client.submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]));
+        let result = client.try_approve_change_by_governance(&1);
+        assert_eq!(result, Err(Ok(Error::InvalidMilestoneStatus)));
+
+        client.vote_milestone(&1, &0, &v1, &true);
+        client.vote_milestone(&1, &0, &v2, &true);
+        client.vote_milestone(&1, &0, &v3, &true);
+        client.approve_change_by_governance(&1);
+        assert!(!client.get_escrow(&1).validators.contains(&newcomer));
+    }
+
+    #[test]
+    fn test_removed_validators_approvals_no_longer_count() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, _, _, validators, client) = setup_with_admin(&env);
+        let (v1, v2, v3) = (
+            validators.get(0).unwrap(),
+            validators.get(1).unwrap(),
+            validators.get(2).unwrap(),
+        );
+        let newcomer = Address::generate(&env);
+
+        client.propose_validator_change(&1, &ValidatorChange::Add(newcomer.clone()));
+        client.approve_validator_change(&1, &v1);
+
+        // The admin swaps v1 out while the proposal is pending
+        let replacement = Address::generate(&env);
+        client.update_validators(
+            &1,
+            &Vec::from_array(&env, [replacement.clone(), v2.clone(), v3.clone()]),
+        );
+        assert_eq!(
+            client.get_validator_proposal(&1).unwrap().approvers.len(),
+            1
+        );
+
+        client.approve_validator_change(&1, &v2);
+        assert!(!client.get_escrow(&1).validators.contains(&newcomer));
+        client.approve_validator_change(&1, &replacement);
+        assert!(client.get_escrow(&1).validators.contains(&newcomer));
+    }
+
+    #[test]
+    fn test_validator_change_rounds_required_approvals_up() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, creator, token, validators, client) = setup_with_admin(&env);
+        client.initialize(&2, &creator, &token, &validators, &6000);
+        let newcomer = Address::generate(&env);
+
+        // 60% of three validators is 1.8, so one approval is not enough
+        client.propose_validator_change(&2, &ValidatorChange::Add(newcomer.clone()));
+        client.approve_validator_change(&2, &validators.get(0).unwrap());
+        assert!(!client.get_escrow(&2).validators.contains(&newcomer));
+        client.approve_validator_change(&2, &validators.get(1).unwrap());
+        assert!(client.get_escrow(&2).validators.contains(&newcomer));
+    }
+
+    #[test]
+    fn test_bonded_candidate_joins_validators_by_proposal() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1000);
+        env.mock_all_auths();
+        let (_, _, token, validators, client) = setup_with_admin(&env);
+        let candidate = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&candidate, &100);
+
+        // Without a minimum bond only validators bond and seats are not for hire
+        let result = client.try_lock_stake(&1, &candidate, &100);
+        assert_eq!(result, Err(Ok(Error::NotAValidator)));
+        let result = client.try_request_validator_seat(&1, &candidate);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+        client.set_bond_config(&ValidatorBondConfig {
+            min_bond: 100,
+            reward_share: 0,
+            slash_share: 0,
+            max_missed_votes: 0,
+            unbonding_period: 0,
+        });
+        client.lock_stake(&1, &candidate, &60);
+        let result = client.try_request_validator_seat(&1, &candidate);
+        assert_eq!(result, Err(Ok(Error::InsufficientFunds)));
+
+        client.lock_stake(&1, &candidate, &40);
+        client.request_validator_seat(&1, &candidate);
+        assert_eq!(
+            client.get_validator_proposal(&1).unwrap().change,
+            ValidatorChange::Add(candidate.clone())
+        );
+
+        // The seat is granted through the usual approval of a proposal
+        client.approve_validator_change(&1, &validators.get(0).unwrap());
+        client.approve_validator_change(&1, &validators.get(1).unwrap());
+        assert!(client.get_escrow(&1).validators.contains(&candidate));
+        assert_eq!(client.get_validator_stake(&1, &candidate), 100);
+        let result = client.try_request_validator_seat(&1, &candidate);
+        assert_eq!(result, Err(Ok(Error::InvalidInput)));
+    }
 
     // ====== NEW tests for Emergency Pause/Resume ======
     #[test]
@@ -545,11 +1751,11 @@ mod tests {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
         env.mock_all_auths();
-        let (admin, _, _, _, client) = setup_with_admin(&env);
+        let (admin, creator, _, _, client) = setup_with_admin(&env);
 
         client.pause(&admin);
 
-        let result = client.try_deposit(&1, &500);
+        let result = client.try_deposit(&1, &creator, &500);
         assert!(result.is_err(), "deposit should be blocked when paused");
     }
 
@@ -558,13 +1764,13 @@ mod tests {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
         env.mock_all_auths();
-        let (admin, _, _, _, client) = setup_with_admin(&env);
+        let (admin, creator, _, _, client) = setup_with_admin(&env);
 
-        client.deposit(&1, &1000); // deposit before pausing
+        client.deposit(&1, &creator, &1000); // deposit before pausing
         client.pause(&admin);
 
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let result = client.try_create_milestone(&1, &description_hash, &500);
+        let result = client.try_create_milestone(&1, &description_hash, &500, &None, &None, &None);
         assert!(result.is_err(), "create_milestone should be blocked when paused");
     }
 
@@ -573,11 +1779,11 @@ mod tests {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
         env.mock_all_auths();
-        let (admin, _, _, _, client) = setup_with_admin(&env);
+        let (admin, creator, _, _, client) = setup_with_admin(&env);
 
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
         client.pause(&admin);
 
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
@@ -590,11 +1796,11 @@ mod tests {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
         env.mock_all_auths();
-        let (admin, _, _, validators, client) = setup_with_admin(&env);
+        let (admin, creator, _, validators, client) = setup_with_admin(&env);
 
-        client.deposit(&1, &1000);
+        client.deposit(&1, &creator, &1000);
         let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.create_milestone(&1, &description_hash, &500);
+        client.create_milestone(&1, &description_hash, &500, &None, &None, &None);
         let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
         client.submit_milestone(&1, &0, &proof_hash);
         client.pause(&admin);
@@ -640,14 +1846,14 @@ mod tests {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
         env.mock_all_auths();
-        let (admin, _, _, _, client) = setup_with_admin(&env);
+        let (admin, creator, _, _, client) = setup_with_admin(&env);
 
         client.pause(&admin);
         env.ledger().set_timestamp(1000 + 86400 + 1);
         client.resume(&admin);
 
         // deposit should work again
-        let result = client.try_deposit(&1, &500);
+        let result = client.try_deposit(&1, &creator, &500);
         assert!(result.is_ok(), "deposit should work after resume");
     }
 
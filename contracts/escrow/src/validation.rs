@@ -1,6 +1,7 @@
 use shared::errors::Error;
-use shared::types::EscrowInfo;
-use soroban_sdk::Address;
+use shared::types::{ChallengeConfig, EscrowInfo, Tranche, ValidatorBondConfig, ValidatorChange};
+use shared::{MAX_APPROVAL_THRESHOLD, MAX_TRANCHES, MIN_APPROVAL_THRESHOLD, MIN_VALIDATORS};
+use soroban_sdk::{Address, Vec};
 
 /// Validate that an address is a validator in the escrow
 pub fn validate_validator(escrow: &EscrowInfo, validator: &Address) -> Result<(), Error> {
@@ -10,3 +11,107 @@ pub fn validate_validator(escrow: &EscrowInfo, validator: &Address) -> Result<()
         Err(Error::NotAValidator)
     }
 }
+
+/// Validate the validator set and approval threshold for a new escrow
+pub fn validate_escrow_config(
+    validators: &Vec<Address>,
+    approval_threshold: u32,
+) -> Result<(), Error> {
+    if validators.len() < MIN_VALIDATORS || has_duplicates(validators) {
+        return Err(Error::InvalidInput);
+    }
+
+    if !(MIN_APPROVAL_THRESHOLD..=MAX_APPROVAL_THRESHOLD).contains(&approval_threshold) {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(())
+}
+
+/// Validate a milestone release schedule: shares add up to 100% and
+/// tranches vest in order
+pub fn validate_tranches(tranches: &Vec<Tranche>) -> Result<(), Error> {
+    if tranches.is_empty() || tranches.len() > MAX_TRANCHES {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut total_share: u32 = 0;
+    let mut previous_delay = 0;
+    for tranche in tranches.iter() {
+        if tranche.share == 0 || tranche.delay < previous_delay {
+            return Err(Error::InvalidInput);
+        }
+        total_share = total_share.saturating_add(tranche.share);
+        previous_delay = tranche.delay;
+    }
+
+    if total_share != 10000 {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(())
+}
+
+/// Validate a challenge window: a dispute needs a non-zero share of deposits
+/// and, when enabled, distinct arbiters to decide it
+pub fn validate_challenge_config(config: &ChallengeConfig) -> Result<(), Error> {
+    if config.dispute_threshold == 0 || config.dispute_threshold > 10000 {
+        return Err(Error::InvalidInput);
+    }
+
+    if config.period > 0 && config.arbiters.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+
+    if has_duplicates(&config.arbiters) {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(())
+}
+
+/// Validate the validator bonding rules: shares are basis points and the
+/// minimum bond cannot be negative
+pub fn validate_bond_config(config: &ValidatorBondConfig) -> Result<(), Error> {
+    if config.min_bond < 0 || config.reward_share > 10000 || config.slash_share > 10000 {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(())
+}
+
+/// Apply a proposed change to a validator set, rejecting duplicates and sets
+/// smaller than `MIN_VALIDATORS`
+pub fn apply_validator_change(
+    validators: &Vec<Address>,
+    change: &ValidatorChange,
+) -> Result<Vec<Address>, Error> {
+    let mut updated = validators.clone();
+    match change {
+        ValidatorChange::Add(validator) => {
+            if validators.contains(validator) {
+                return Err(Error::InvalidInput);
+            }
+            updated.push_back(validator.clone());
+        }
+        ValidatorChange::Remove(validator) => {
+            let index = validators
+                .first_index_of(validator)
+                .ok_or(Error::NotAValidator)?;
+            updated.remove(index);
+            if updated.len() < MIN_VALIDATORS {
+                return Err(Error::InvalidInput);
+            }
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Check if an address list holds the same address more than once
+fn has_duplicates(addresses: &Vec<Address>) -> bool {
+    addresses
+        .iter()
+        .enumerate()
+        .any(|(index, address)| addresses.first_index_of(&address) != Some(index as u32))
+}
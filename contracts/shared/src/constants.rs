@@ -25,6 +25,22 @@ pub const MILESTONE_APPROVAL_THRESHOLD: u32 = 6000;
 /// Minimum validators required
 pub const MIN_VALIDATORS: u32 = 3;
 
+/// Bit set in the ids of escrows funded by ProjectLaunch, keeping them apart
+/// from escrows initialized directly
+pub const LAUNCH_ESCROW_ID_FLAG: u64 = 1 << 63;
+
+/// Maximum number of tranches in a milestone release schedule
+pub const MAX_TRANCHES: u32 = 10;
+
+/// Times a rejected milestone may be resubmitted unless the admin sets otherwise
+pub const DEFAULT_MAX_RESUBMISSIONS: u32 = 2;
+
+/// Share of escrow deposits needed to dispute an approved milestone (10%)
+pub const DEFAULT_DISPUTE_THRESHOLD: u32 = 1000;
+
+/// Fixed-point scale of the failed-milestone refund owed per deposited unit
+pub const REFUND_RATE_SCALE: i128 = 1_000_000_000_000;
+
 /// Reputation score ranges
 pub const REPUTATION_MIN: i128 = 0;
 pub const REPUTATION_MAX: i128 = 10000;
@@ -5,10 +5,21 @@ pub const PROJECT_CREATED: Symbol = symbol_short!("proj_new");
 pub const PROJECT_FUNDED: Symbol = symbol_short!("proj_fund");
 pub const PROJECT_COMPLETED: Symbol = symbol_short!("proj_done");
 pub const PROJECT_FAILED: Symbol = symbol_short!("proj_fail");
+pub const PROJECT_CANCELLED: Symbol = symbol_short!("proj_canc");
+pub const STRETCH_GOAL_REACHED: Symbol = symbol_short!("stretch");
+pub const PLATFORM_FEE_COLLECTED: Symbol = symbol_short!("fee_coll");
+pub const EXTENSION_REQUESTED: Symbol = symbol_short!("ext_req");
+pub const EXTENSION_VOTED: Symbol = symbol_short!("ext_vote");
+pub const DEADLINE_EXTENDED: Symbol = symbol_short!("ext_done");
+pub const EXTENSION_REJECTED: Symbol = symbol_short!("ext_rej");
+pub const EXTENSION_CANCELLED: Symbol = symbol_short!("ext_canc");
+pub const ESCROW_DEPOSIT_PENDING: Symbol = symbol_short!("esc_pend");
 
 // Contribution events
 pub const CONTRIBUTION_MADE: Symbol = symbol_short!("contrib");
 pub const REFUND_ISSUED: Symbol = symbol_short!("refund");
+pub const CONTRIBUTION_WITHDRAWN: Symbol = symbol_short!("withdraw");
+pub const CONTRIBUTION_TRANSFERRED: Symbol = symbol_short!("contr_xfr");
 
 // Escrow events
 pub const ESCROW_INITIALIZED: Symbol = symbol_short!("esc_init");
@@ -18,8 +29,17 @@ pub const MILESTONE_CREATED: Symbol = symbol_short!("m_create");
 pub const MILESTONE_SUBMITTED: Symbol = symbol_short!("m_submit");
 pub const MILESTONE_APPROVED: Symbol = symbol_short!("m_apprv");
 pub const MILESTONE_REJECTED: Symbol = symbol_short!("m_reject");
+pub const MILESTONE_FAILED: Symbol = symbol_short!("m_failed");
+pub const MILESTONE_EXPIRED: Symbol = symbol_short!("m_expired");
+pub const RELEASE_PENDING: Symbol = symbol_short!("rel_pend");
+pub const MILESTONE_DISPUTED: Symbol = symbol_short!("m_dispute");
+pub const DISPUTE_RESOLVED: Symbol = symbol_short!("disp_res");
 pub const MILESTONE_COMPLETED: Symbol = symbol_short!("milestone");
 pub const VALIDATORS_UPDATED: Symbol = symbol_short!("v_update");
+pub const VALIDATOR_CHANGE_PROPOSED: Symbol = symbol_short!("v_propose");
+pub const ESCROW_ABANDONED: Symbol = symbol_short!("esc_abdn");
+pub const VALIDATOR_REWARDED: Symbol = symbol_short!("v_reward");
+pub const VALIDATOR_SLASHED: Symbol = symbol_short!("v_slashed");
 
 // Distribution events
 pub const PROFIT_DISTRIBUTED: Symbol = symbol_short!("profit");
@@ -14,7 +14,7 @@ pub type Hash = BytesN<32>;
 
 /// Platform fee configuration
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
     pub platform_fee: BasisPoints, // Platform fee in basis points
     pub creator_fee: BasisPoints,  // Creator fee in basis points
@@ -71,7 +71,20 @@ pub enum MilestoneStatus {
     Pending = 0,   // Created, awaiting submission
     Submitted = 1, // Submitted with proof, awaiting validator votes
     Approved = 2,  // Approved by majority, funds released
-    Rejected = 3,  // Rejected by majority
+    Rejected = 3,  // Rejected by majority, may be resubmitted
+    Failed = 4,    // Rejected with no resubmissions left, amount refundable
+    Expired = 5,   // Missed its deadline, amount no longer allocated
+    PendingRelease = 6, // Approved, funds held until the challenge window closes
+    Disputed = 7,  // Challenged by backers, funds frozen until arbiters decide
+}
+
+/// How validator votes are weighted against the approval threshold
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VoteWeighting {
+    Equal = 0,      // One vote per validator
+    Stake = 1,      // Weighted by stake locked in the escrow
+    Reputation = 2, // Weighted by ReputationContract score
 }
 
 /// Milestone structure
@@ -87,6 +100,136 @@ pub struct Milestone {
     pub approval_count: u32,
     pub rejection_count: u32,
     pub created_at: Timestamp,
+    pub resubmission_count: u32,
+    /// Proof must be submitted by this time
+    pub deadline: Option<Timestamp>,
+    /// Seconds validators have to decide after each submission
+    pub voting_window: Option<u64>,
+    /// Time of the latest submission (0 until submitted)
+    pub submitted_at: Timestamp,
+    /// Release schedule applied once approved
+    pub tranches: Vec<Tranche>,
+    /// Time of approval (0 until approved)
+    pub approved_at: Timestamp,
+    /// Amount paid out to the creator so far
+    pub released_amount: Amount,
+    /// Number of leading tranches paid out
+    pub tranches_released: u32,
+    /// End of the backer challenge window (0 unless pending release)
+    pub challenge_ends_at: Timestamp,
+    /// Combined weight of the validators snapshotted at submission
+    pub total_weight: Amount,
+    pub approval_weight: Amount,
+    pub rejection_weight: Amount,
+}
+
+/// One step of a milestone's release schedule
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tranche {
+    /// Share of the milestone amount, in basis points
+    pub share: BasisPoints,
+    /// Seconds after approval before the tranche vests
+    pub delay: u64,
+}
+
+/// Release progress of one tranche of a milestone
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrancheStatus {
+    pub amount: Amount,
+    /// Time the tranche vests (0 until the milestone is approved)
+    pub vests_at: Timestamp,
+    pub released: bool,
+}
+
+/// Challenge window applied to approved milestones before funds are released
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChallengeConfig {
+    /// Seconds backers have to dispute an approval (0 releases immediately)
+    pub period: u64,
+    /// Share of escrow deposits needed to open a dispute, in basis points
+    pub dispute_threshold: BasisPoints,
+    /// Addresses that decide disputed milestones by majority
+    pub arbiters: Vec<Address>,
+}
+
+/// Change to an escrow's validator set
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidatorChange {
+    Add(Address),
+    Remove(Address),
+}
+
+/// Validator set change proposed by an escrow's creator
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorProposal {
+    pub id: u32,
+    pub change: ValidatorChange,
+    /// Validators who approved the change; only those still validating count
+    pub approvers: Vec<Address>,
+    pub proposed_at: Timestamp,
+}
+
+/// Bonding, reward and slashing rules for escrow validators
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorBondConfig {
+    /// Stake a validator must lock to review submissions (0 = no bond required)
+    pub min_bond: Amount,
+    /// Share of each release paid to the validators who voted, in basis points
+    pub reward_share: BasisPoints,
+    /// Share of a validator's bond taken per offence, in basis points
+    pub slash_share: BasisPoints,
+    /// Expired votes a validator may miss before being slashed (0 = never)
+    pub max_missed_votes: u32,
+    /// Seconds withdrawn stake stays locked and slashable
+    pub unbonding_period: u64,
+}
+
+/// Stake a validator withdrew, waiting out the unbonding period
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unbonding {
+    pub amount: Amount,
+    pub available_at: Timestamp,
+}
+
+/// Funds of an abandoned escrow as they stood when it was abandoned, which
+/// backers' refunds are split against
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbandonmentSnapshot {
+    pub total_deposited: Amount,
+    /// Funds neither released, vesting to the creator nor owed to backers
+    /// for failed milestones
+    pub refundable: Amount,
+}
+
+/// Backer challenge of a milestone approval
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneDispute {
+    /// Deposits of the backers supporting the dispute
+    pub stake: Amount,
+    /// Arbiters configured when the dispute was opened, who decide it
+    pub arbiters: Vec<Address>,
+    pub approvals: u32,
+    pub rejections: u32,
+}
+
+/// Outcome of one validator review round of a milestone submission
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneReview {
+    pub proof_hash: Hash,
+    pub status: MilestoneStatus,
+    pub approval_count: u32,
+    pub rejection_count: u32,
+    pub decided_at: Timestamp,
 }
 
 /// Proposal status
@@ -1,5 +1,6 @@
 use soroban_sdk::Env;
 
+use crate::constants::LAUNCH_ESCROW_ID_FLAG;
 use crate::types::{Amount, BasisPoints};
 
 /// Calculate percentage of an amount
@@ -12,6 +13,11 @@ pub fn calculate_fee(amount: Amount, fee_rate: BasisPoints) -> Amount {
     calculate_percentage(amount, fee_rate)
 }
 
+/// Escrow id under which ProjectLaunch funds the escrow of a project
+pub fn launch_escrow_id(project_id: u64) -> u64 {
+    project_id | LAUNCH_ESCROW_ID_FLAG
+}
+
 /// Verify timestamp is in the future
 pub fn verify_future_timestamp(env: &Env, timestamp: u64) -> bool {
     timestamp > env.ledger().timestamp()